@@ -0,0 +1,175 @@
+use crate::registry::Part;
+use crate::submit::Verdict;
+use color_eyre::eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::Path;
+
+/// What the site has said about one part's answers so far
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartAttempts {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+    /// The smallest answer known to be too high
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub too_high: Option<i64>,
+    /// The largest answer known to be too low
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub too_low: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correct: Option<String>,
+}
+
+/// Why an answer isn't worth submitting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadySolved(String),
+    KnownWrong,
+    TooHigh(i64),
+    TooLow(i64),
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadySolved(correct) => write!(f, "already solved with {}", correct),
+            Rejection::KnownWrong => write!(f, "it has already been submitted and was wrong"),
+            Rejection::TooHigh(bound) => write!(f, "{} was already too high", bound),
+            Rejection::TooLow(bound) => write!(f, "{} was already too low", bound),
+        }
+    }
+}
+
+impl PartAttempts {
+    pub fn rejects(&self, answer: &str) -> Option<Rejection> {
+        if let Some(correct) = &self.correct {
+            return Some(Rejection::AlreadySolved(correct.clone()));
+        }
+        if self.wrong.iter().any(|wrong| wrong == answer) {
+            return Some(Rejection::KnownWrong);
+        }
+        let value: i64 = answer.parse().ok()?;
+        match (self.too_high, self.too_low) {
+            (Some(bound), _) if value >= bound => Some(Rejection::TooHigh(bound)),
+            (_, Some(bound)) if value <= bound => Some(Rejection::TooLow(bound)),
+            _ => None,
+        }
+    }
+
+    pub fn record(&mut self, answer: &str, verdict: &Verdict) {
+        match verdict {
+            Verdict::Correct => self.correct = Some(answer.to_string()),
+            Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow => {
+                self.wrong.push(answer.to_string());
+                let Ok(value) = answer.parse::<i64>() else {
+                    return;
+                };
+                if *verdict == Verdict::TooHigh {
+                    self.too_high = Some(self.too_high.map_or(value, |high| high.min(value)));
+                } else if *verdict == Verdict::TooLow {
+                    self.too_low = Some(self.too_low.map_or(value, |low| low.max(value)));
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// A day's submission history, kept in `data/attempts.toml` so known-bad answers are never
+/// sent twice:
+///
+/// ```toml
+/// [b]
+/// wrong = ["13985281920"]
+/// too_high = 13985281920
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempts {
+    #[serde(flatten)]
+    parts: BTreeMap<String, PartAttempts>,
+}
+
+impl Attempts {
+    /// A day that's never been submitted has no file, which is the same as an empty history
+    pub fn load(path: &Path) -> Result<Attempts> {
+        if !path.exists() {
+            return Ok(Attempts::default());
+        }
+        let contents = std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("reading {}", path.display()))?;
+        toml::from_str(&contents).wrap_err_with(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let contents = toml::to_string(self)?;
+        std::fs::write(path, contents).wrap_err_with(|| format!("writing {}", path.display()))
+    }
+
+    pub fn part(&mut self, part: Part) -> &mut PartAttempts {
+        self.parts.entry(part.to_string()).or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_rejects_known_answers() {
+        let attempts = PartAttempts {
+            wrong: vec!["ABC".to_string(), "60".to_string(), "10".to_string()],
+            too_high: Some(60),
+            too_low: Some(10),
+            correct: None,
+        };
+        assert_eq!(Some(Rejection::KnownWrong), attempts.rejects("ABC"));
+        assert_eq!(Some(Rejection::TooHigh(60)), attempts.rejects("75"));
+        assert_eq!(Some(Rejection::TooLow(10)), attempts.rejects("-4"));
+        assert_eq!(None, attempts.rejects("42"));
+        assert_eq!(None, attempts.rejects("XYZ"));
+    }
+
+    #[test]
+    fn test_rejects_once_solved() {
+        let attempts = PartAttempts {
+            correct: Some("42".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            Some(Rejection::AlreadySolved("42".to_string())),
+            attempts.rejects("43")
+        );
+    }
+
+    #[test]
+    fn test_record_narrows_bounds() {
+        let mut attempts = PartAttempts::default();
+        attempts.record("100", &Verdict::TooHigh);
+        attempts.record("80", &Verdict::TooHigh);
+        attempts.record("90", &Verdict::TooHigh);
+        attempts.record("5", &Verdict::TooLow);
+        attempts.record("50", &Verdict::Wrong);
+        attempts.record("70", &Verdict::RateLimited(None));
+        assert_eq!(Some(80), attempts.too_high);
+        assert_eq!(Some(5), attempts.too_low);
+        assert_eq!(vec!["100", "80", "90", "5", "50"], attempts.wrong);
+        attempts.record("60", &Verdict::Correct);
+        assert_eq!(Some("60".to_string()), attempts.correct);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut attempts = Attempts::default();
+        attempts
+            .part(Part::B)
+            .record("13985281920", &Verdict::TooHigh);
+        let contents = toml::to_string(&attempts).unwrap();
+        assert_eq!(
+            "[b]\nwrong = [\"13985281920\"]\ntoo_high = 13985281920\n",
+            contents
+        );
+        assert_eq!(attempts, toml::from_str(&contents).unwrap());
+    }
+}
@@ -0,0 +1,296 @@
+use crate::ledger::Ledger;
+use crate::registry::{DayEntry, Part, Solution};
+use aoc_helpers::AOCFileOrParseError;
+use color_eyre::eyre::Report;
+use std::any::Any;
+use std::fmt::Display;
+use std::io::ErrorKind;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass(String),
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// Calculated, but the ledger has nothing to compare it to
+    Unknown(String),
+    Panicked(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Pass(actual) => write!(f, "{}", actual),
+            Outcome::Fail { expected, actual } => {
+                write!(f, "expected {}, got {}", expected, actual)
+            }
+            Outcome::Unknown(actual) => write!(f, "{} (not in the ledger)", actual),
+            Outcome::Panicked(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Outcome {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Outcome::Pass(_) => "PASS",
+            Outcome::Fail { .. } => "FAIL",
+            Outcome::Unknown(_) => "UNKNOWN",
+            Outcome::Panicked(_) => "PANICKED",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayCheck {
+    /// The input hasn't been downloaded, so there was nothing to check
+    Skipped(String),
+    /// The input is there, but the day couldn't parse it
+    LoadFailed(String),
+    Checked(Vec<(Part, Outcome)>),
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked without a message".to_string()
+    }
+}
+
+/// Runs `f`, turning a panic into its message
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    catch_unwind(AssertUnwindSafe(f)).map_err(panic_message)
+}
+
+/// Compares one part against the ledger. Parts that don't exist have no outcome.
+pub fn check_part(
+    solution: &dyn Solution,
+    part: Part,
+    expected: Option<String>,
+) -> Option<Outcome> {
+    match catch_panic(|| solution.answer(part)) {
+        Err(message) => Some(Outcome::Panicked(message)),
+        Ok(None) => None,
+        Ok(Some(actual)) => Some(match expected {
+            None => Outcome::Unknown(actual),
+            Some(expected) if expected == actual => Outcome::Pass(actual),
+            Some(expected) => Outcome::Fail { expected, actual },
+        }),
+    }
+}
+
+/// Whether a day failed to load only because its input isn't there
+fn is_missing_input(error: &Report) -> bool {
+    matches!(
+        error.downcast_ref::<AOCFileOrParseError>(),
+        Some(AOCFileOrParseError::File { source, .. }) if source.kind() == ErrorKind::NotFound
+    )
+}
+
+pub fn check_day(entry: &DayEntry, ledger: &Ledger) -> DayCheck {
+    let solution = match catch_panic(|| entry.build()) {
+        Ok(Ok(solution)) => solution,
+        Ok(Err(e)) if is_missing_input(&e) => return DayCheck::Skipped(e.to_string()),
+        Ok(Err(e)) => return DayCheck::LoadFailed(e.to_string()),
+        Err(message) => {
+            return DayCheck::Checked(
+                Part::BOTH
+                    .into_iter()
+                    .map(|part| (part, Outcome::Panicked(message.clone())))
+                    .collect(),
+            )
+        }
+    };
+    DayCheck::Checked(
+        Part::BOTH
+            .into_iter()
+            .filter_map(|part| {
+                check_part(solution.as_ref(), part, ledger.expected(entry.day, part))
+                    .map(|outcome| (part, outcome))
+            })
+            .collect(),
+    )
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub unknown: usize,
+    pub panicked: usize,
+    pub load_failed: usize,
+    pub skipped: usize,
+}
+
+impl Summary {
+    pub fn add(&mut self, check: &DayCheck) {
+        match check {
+            DayCheck::Skipped(_) => self.skipped += 1,
+            DayCheck::LoadFailed(_) => self.load_failed += 1,
+            DayCheck::Checked(outcomes) => {
+                for (_, outcome) in outcomes {
+                    match outcome {
+                        Outcome::Pass(_) => self.passed += 1,
+                        Outcome::Fail { .. } => self.failed += 1,
+                        Outcome::Unknown(_) => self.unknown += 1,
+                        Outcome::Panicked(_) => self.panicked += 1,
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.failed == 0 && self.panicked == 0 && self.load_failed == 0
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} unknown, {} panicked, {} days failed to load, {} days skipped",
+            self.passed,
+            self.failed,
+            self.unknown,
+            self.panicked,
+            self.load_failed,
+            self.skipped
+        )
+    }
+}
+
+pub fn print_day_check(name: &str, check: &DayCheck) {
+    match check {
+        DayCheck::Skipped(reason) => println!("{:<9}{:<14}{}", "SKIPPED", name, reason),
+        DayCheck::LoadFailed(reason) => println!("{:<9}{:<14}{}", "FAILED", name, reason),
+        DayCheck::Checked(outcomes) => {
+            for (part, outcome) in outcomes {
+                println!(
+                    "{:<9}{:<14}{}",
+                    outcome.label(),
+                    format!("{}{}", name, part),
+                    outcome
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::all_days;
+    use aoc_helpers::Answer;
+    use pretty_assertions::assert_eq;
+
+    struct FakeSolution;
+
+    impl Solution for FakeSolution {
+        fn solve(&self, part: Part) -> Answer {
+            match part {
+                Part::A => Answer::from(42_usize),
+                Part::B => panic!("part b is broken"),
+            }
+        }
+    }
+
+    struct OnlyPartA;
+
+    impl Solution for OnlyPartA {
+        fn solve(&self, part: Part) -> Answer {
+            match part {
+                Part::A => Answer::from(7_usize),
+                Part::B => Answer::None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_check_part() {
+        let solution = FakeSolution;
+        assert_eq!(
+            Some(Outcome::Pass("42".to_string())),
+            check_part(&solution, Part::A, Some("42".to_string()))
+        );
+        assert_eq!(
+            Some(Outcome::Fail {
+                expected: "41".to_string(),
+                actual: "42".to_string()
+            }),
+            check_part(&solution, Part::A, Some("41".to_string()))
+        );
+        assert_eq!(
+            Some(Outcome::Unknown("42".to_string())),
+            check_part(&solution, Part::A, None)
+        );
+        assert_eq!(
+            Some(Outcome::Panicked("part b is broken".to_string())),
+            check_part(&solution, Part::B, Some("1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_missing_part_has_no_outcome() {
+        assert_eq!(None, check_part(&OnlyPartA, Part::B, None));
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut summary = Summary::default();
+        summary.add(&DayCheck::Skipped("no input".to_string()));
+        summary.add(&DayCheck::Checked(vec![
+            (Part::A, Outcome::Pass("1".to_string())),
+            (Part::B, Outcome::Unknown("2".to_string())),
+        ]));
+        assert!(summary.is_success());
+        summary.add(&DayCheck::Checked(vec![(
+            Part::A,
+            Outcome::Panicked("oops".to_string()),
+        )]));
+        assert!(!summary.is_success());
+        assert_eq!(
+            "1 passed, 0 failed, 1 unknown, 1 panicked, 0 days failed to load, 1 days skipped",
+            summary.to_string()
+        );
+    }
+
+    fn day_with_input(input_file: &str) -> DayEntry {
+        let mut entry = all_days()
+            .into_iter()
+            .find(|entry| entry.year == 2023 && entry.day == 2)
+            .unwrap();
+        entry.input_file = input_file.to_string();
+        entry
+    }
+
+    #[test]
+    fn test_missing_input_is_skipped() {
+        let entry = day_with_input("/nonexistent/aoc-check/input_data.txt");
+        let day_check = check_day(&entry, &Ledger::default());
+        assert!(matches!(day_check, DayCheck::Skipped(_)));
+        let mut summary = Summary::default();
+        summary.add(&day_check);
+        assert!(summary.is_success());
+    }
+
+    #[test]
+    fn test_malformed_input_fails() {
+        let input_file =
+            std::env::temp_dir().join(format!("aoc-check-malformed-{}.txt", std::process::id()));
+        std::fs::write(&input_file, "this is not a list of games\n").unwrap();
+        let entry = day_with_input(input_file.to_str().unwrap());
+        let day_check = check_day(&entry, &Ledger::default());
+        std::fs::remove_file(&input_file).unwrap();
+        assert!(matches!(day_check, DayCheck::LoadFailed(_)));
+        let mut summary = Summary::default();
+        summary.add(&day_check);
+        assert!(!summary.is_success());
+        assert_eq!(1, summary.load_failed);
+    }
+}
@@ -0,0 +1,140 @@
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/triorph/AOC runner by triorph@gmail.com";
+
+/// Makes sure consecutive requests are at least `interval` apart
+#[derive(Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    last: Option<Instant>,
+}
+
+impl RateLimiter {
+    pub fn new(interval: Duration) -> RateLimiter {
+        RateLimiter {
+            interval,
+            last: None,
+        }
+    }
+
+    /// Sleeps until the next request is allowed, and counts it as made
+    pub fn wait(&mut self) {
+        if let Some(last) = self.last {
+            if let Some(remaining) = self.interval.checked_sub(last.elapsed()) {
+                thread::sleep(remaining);
+            }
+        }
+        self.last = Some(Instant::now());
+    }
+}
+
+/// Talks to the Advent of Code site, or anything pretending to be it at `base_url`
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    limiter: RateLimiter,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, interval: Duration) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            limiter: RateLimiter::new(interval),
+        }
+    }
+
+    fn url(&self, year: usize, day: usize, path: &str) -> String {
+        format!("{}/{}/day/{}/{}", self.base_url, year, day, path)
+    }
+
+    fn send(&mut self, request: ureq::Request, form: Option<&[(&str, &str)]>) -> Result<String> {
+        self.limiter.wait();
+        let url = request.url().to_string();
+        let request = request.set("Cookie", &format!("session={}", self.session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => response
+                .into_string()
+                .wrap_err_with(|| format!("reading the response from {}", url)),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(eyre!("{} returned {}: {}", url, code, body.trim()))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// A day's puzzle input, exactly as served
+    pub fn input(&mut self, year: usize, day: usize) -> Result<String> {
+        let request = self.agent.get(&self.url(year, day, "input"));
+        self.send(request, None)
+    }
+
+    /// Posts an answer for a part (level 1 or 2), returning the page that says how it went
+    pub fn answer(
+        &mut self,
+        year: usize,
+        day: usize,
+        level: usize,
+        answer: &str,
+    ) -> Result<String> {
+        let request = self.agent.post(&self.url(year, day, "answer"));
+        let level = level.to_string();
+        self.send(request, Some(&[("level", &level), ("answer", answer)]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::TestServer;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_rate_limiter() {
+        let mut limiter = RateLimiter::new(Duration::from_millis(50));
+        let start = Instant::now();
+        limiter.wait();
+        assert!(start.elapsed() < Duration::from_millis(50));
+        limiter.wait();
+        limiter.wait();
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_input_request() {
+        let server = TestServer::serve(vec![(200, "1\n2\n3\n".to_string())]);
+        let mut client = Client::new(&server.base_url, "abc123", Duration::ZERO);
+        assert_eq!("1\n2\n3\n", client.input(2022, 1).unwrap());
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(requests[0].contains("session=abc123"));
+    }
+
+    #[test]
+    fn test_answer_request() {
+        let server = TestServer::serve(vec![(200, "<article></article>".to_string())]);
+        let mut client = Client::new(&server.base_url, "abc123", Duration::ZERO);
+        client.answer(2024, 5, 2, "4872").unwrap();
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2024/day/5/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("level=2&answer=4872"));
+    }
+
+    #[test]
+    fn test_error_status() {
+        let server = TestServer::serve(vec![(404, "Not found".to_string())]);
+        let mut client = Client::new(&server.base_url, "abc123", Duration::ZERO);
+        let error = client.input(2030, 1).unwrap_err().to_string();
+        assert!(error.ends_with("/2030/day/1/input returned 404: Not found"));
+    }
+}
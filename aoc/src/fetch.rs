@@ -0,0 +1,175 @@
+use crate::client::Client;
+use crate::registry::DayEntry;
+use color_eyre::eyre::{Result, WrapErr};
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Every input that has been downloaded, kept outside the day crates so a deleted or
+/// overwritten `input_data.txt` can be restored without asking the server again
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> InputCache {
+        InputCache { dir: dir.into() }
+    }
+
+    /// Sits next to the runner, and is ignored by git
+    pub fn default_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("cache")
+    }
+
+    fn path(&self, year: usize, day: usize) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{}.txt", day))
+    }
+
+    pub fn get(&self, year: usize, day: usize) -> Option<String> {
+        fs::read_to_string(self.path(year, day)).ok()
+    }
+
+    pub fn put(&self, year: usize, day: usize, contents: &str) -> Result<()> {
+        write_file(&self.path(year, day), contents)
+    }
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).wrap_err_with(|| format!("creating {}", parent.display()))?;
+    }
+    fs::write(path, contents).wrap_err_with(|| format!("writing {}", path.display()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchOutcome {
+    AlreadyPresent,
+    FromCache,
+    Downloaded,
+}
+
+impl Display for FetchOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchOutcome::AlreadyPresent => write!(f, "input already exists"),
+            FetchOutcome::FromCache => write!(f, "restored input from the cache"),
+            FetchOutcome::Downloaded => write!(f, "downloaded input"),
+        }
+    }
+}
+
+/// Makes sure a day's `input_data.txt` exists, only downloading it if it's neither there nor
+/// cached. `refresh` downloads it regardless.
+pub fn fetch_day(
+    entry: &DayEntry,
+    client: &mut Client,
+    cache: &InputCache,
+    refresh: bool,
+) -> Result<FetchOutcome> {
+    if !refresh && Path::new(&entry.input_file).exists() {
+        return Ok(FetchOutcome::AlreadyPresent);
+    }
+    let (contents, outcome) = match cache.get(entry.year, entry.day).filter(|_| !refresh) {
+        Some(contents) => (contents, FetchOutcome::FromCache),
+        None => {
+            let contents = client.input(entry.year, entry.day)?;
+            cache.put(entry.year, entry.day, &contents)?;
+            (contents, FetchOutcome::Downloaded)
+        }
+    };
+    write_file(Path::new(&entry.input_file), &contents)?;
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::all_days;
+    use crate::test_server::TestServer;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// A real registry entry, but with its input somewhere disposable
+    fn entry_in(dir: &Path) -> DayEntry {
+        let mut entry = all_days().swap_remove(0);
+        entry.input_file = dir
+            .join("day1/data/input_data.txt")
+            .to_string_lossy()
+            .to_string();
+        entry
+    }
+
+    #[test]
+    fn test_fetch_downloads_then_uses_the_cache() {
+        let dir = temp_dir("cache");
+        let entry = entry_in(&dir);
+        let cache = InputCache::new(dir.join("cache"));
+        let server = TestServer::serve(vec![(200, "1000\n2000\n".to_string())]);
+        let mut client = Client::new(&server.base_url, "abc123", Duration::ZERO);
+
+        let outcome = fetch_day(&entry, &mut client, &cache, false).unwrap();
+        assert_eq!(FetchOutcome::Downloaded, outcome);
+        assert_eq!(
+            "1000\n2000\n",
+            fs::read_to_string(&entry.input_file).unwrap()
+        );
+
+        let outcome = fetch_day(&entry, &mut client, &cache, false).unwrap();
+        assert_eq!(FetchOutcome::AlreadyPresent, outcome);
+
+        fs::remove_file(&entry.input_file).unwrap();
+        let outcome = fetch_day(&entry, &mut client, &cache, false).unwrap();
+        assert_eq!(FetchOutcome::FromCache, outcome);
+        assert_eq!(
+            "1000\n2000\n",
+            fs::read_to_string(&entry.input_file).unwrap()
+        );
+
+        assert_eq!(1, server.requests().len());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_refresh() {
+        let dir = temp_dir("refresh");
+        let entry = entry_in(&dir);
+        let cache = InputCache::new(dir.join("cache"));
+        cache.put(entry.year, entry.day, "stale\n").unwrap();
+        write_file(Path::new(&entry.input_file), "stale\n").unwrap();
+        let server = TestServer::serve(vec![(200, "fresh\n".to_string())]);
+        let mut client = Client::new(&server.base_url, "abc123", Duration::ZERO);
+
+        let outcome = fetch_day(&entry, &mut client, &cache, true).unwrap();
+        assert_eq!(FetchOutcome::Downloaded, outcome);
+        assert_eq!("fresh\n", fs::read_to_string(&entry.input_file).unwrap());
+        assert_eq!(
+            Some("fresh\n".to_string()),
+            cache.get(entry.year, entry.day)
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_failure_writes_nothing() {
+        let dir = temp_dir("failure");
+        let entry = entry_in(&dir);
+        let cache = InputCache::new(dir.join("cache"));
+        let server = TestServer::serve(vec![(400, "Puzzle inputs differ by user.".to_string())]);
+        let mut client = Client::new(&server.base_url, "expired", Duration::ZERO);
+
+        assert!(fetch_day(&entry, &mut client, &cache, false).is_err());
+        assert!(!Path::new(&entry.input_file).exists());
+        assert_eq!(None, cache.get(entry.year, entry.day));
+        server.requests();
+        // Nothing was written, so the directory might never have been made
+        let _ = fs::remove_dir_all(dir);
+    }
+}
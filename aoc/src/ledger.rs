@@ -0,0 +1,108 @@
+use crate::registry::Part;
+use color_eyre::eyre::{Result, WrapErr};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+enum LedgerAnswer {
+    Integer(i64),
+    String(String),
+}
+
+impl Display for LedgerAnswer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LedgerAnswer::Integer(value) => write!(f, "{}", value),
+            LedgerAnswer::String(value) => write!(f, "{}", value.trim_end_matches('\n')),
+        }
+    }
+}
+
+/// The confirmed answers for one year, read from its `answers.toml`:
+///
+/// ```toml
+/// [day5]
+/// a = 4872
+/// b = "CMZ"
+/// ```
+///
+/// Multi-line answers (ascii art) can be written as `"""` strings.
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct Ledger {
+    #[serde(flatten)]
+    days: BTreeMap<String, BTreeMap<String, LedgerAnswer>>,
+}
+
+impl Ledger {
+    /// Reads a year's ledger. A missing file is an empty ledger, so every answer is unknown.
+    pub fn load(path: &str) -> Result<Ledger> {
+        if !Path::new(path).exists() {
+            return Ok(Ledger::default());
+        }
+        let contents =
+            std::fs::read_to_string(path).wrap_err_with(|| format!("reading {}", path))?;
+        Ledger::parse(&contents).wrap_err_with(|| format!("parsing {}", path))
+    }
+
+    pub fn parse(contents: &str) -> Result<Ledger> {
+        Ok(toml::from_str(contents)?)
+    }
+
+    pub fn expected(&self, day: usize, part: Part) -> Option<String> {
+        self.days
+            .get(&format!("day{}", day))
+            .and_then(|parts| parts.get(&part.to_string()))
+            .map(|answer| answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse() {
+        let ledger = Ledger::parse(
+            r#"
+# a comment
+[day5]
+a = 4872
+b = "CMZ"
+
+[day10]
+b = """
+#..#
+####
+"""
+"#,
+        )
+        .unwrap();
+        assert_eq!(Some("4872".to_string()), ledger.expected(5, Part::A));
+        assert_eq!(Some("CMZ".to_string()), ledger.expected(5, Part::B));
+        assert_eq!(None, ledger.expected(10, Part::A));
+        assert_eq!(Some("#..#\n####".to_string()), ledger.expected(10, Part::B));
+        assert_eq!(None, ledger.expected(11, Part::A));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Ledger::parse("[day5]\na = [1, 2]").is_err());
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let ledger = Ledger::load("does/not/exist.toml").unwrap();
+        assert_eq!(Ledger::default(), ledger);
+    }
+
+    #[test]
+    fn test_real_ledgers_parse() {
+        for entry in crate::registry::all_days() {
+            Ledger::load(&entry.ledger_file).unwrap();
+        }
+    }
+}
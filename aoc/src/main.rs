@@ -0,0 +1,364 @@
+mod attempts;
+mod check;
+mod client;
+mod fetch;
+mod ledger;
+mod pool;
+mod registry;
+mod scaffold;
+mod selector;
+mod submit;
+#[cfg(test)]
+mod test_server;
+mod timing;
+use aoc_helpers::answer::print_answer;
+use aoc_helpers::Answer;
+use clap::{Args, Parser, Subcommand};
+use color_eyre::eyre::{bail, eyre, Result};
+use ledger::Ledger;
+use registry::{DayEntry, Part};
+use selector::{DayId, Selector};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+#[command(
+    about = "Runs Advent of Code solutions from every year",
+    args_conflicts_with_subcommands = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Downloads the puzzle inputs for the selected days that don't have one yet
+    Fetch(FetchArgs),
+    /// Submits a part's answer, unless earlier attempts already rule it out
+    Submit(SubmitArgs),
+    /// Creates a new day from the year's skeleton and registers it with the runner
+    NewDay(NewDayArgs),
+}
+
+/// Where the puzzles are served from, and who is asking
+#[derive(Args, Debug)]
+struct ServerArgs {
+    /// The session cookie of a logged in browser
+    #[arg(long, env = "SESSION", hide_env_values = true)]
+    session: String,
+
+    /// Point at a stand-in server instead of the real site
+    #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// The minimum time between requests, in milliseconds
+    #[arg(long, default_value_t = 1000)]
+    interval_ms: u64,
+}
+
+impl ServerArgs {
+    fn client(&self) -> client::Client {
+        client::Client::new(
+            &self.base_url,
+            &self.session,
+            Duration::from_millis(self.interval_ms),
+        )
+    }
+}
+
+#[derive(Args, Debug)]
+struct FetchArgs {
+    /// Days to fetch, written the same way as for running them. Fetches every day when empty
+    selectors: Vec<Selector>,
+
+    #[command(flatten)]
+    server: ServerArgs,
+
+    /// Downloaded inputs are kept here too, so they never have to be fetched twice
+    #[arg(long, default_value_os_t = fetch::InputCache::default_dir())]
+    cache_dir: PathBuf,
+
+    /// Download again even if the input exists or is cached
+    #[arg(long)]
+    refresh: bool,
+}
+
+#[derive(Args, Debug)]
+struct SubmitArgs {
+    /// The day to submit, e.g. `2024:day5`
+    day: DayId,
+
+    /// `a` or `b`
+    part: Part,
+
+    #[command(flatten)]
+    server: ServerArgs,
+
+    /// Submit this instead of the calculated answer, e.g. ascii art that's been read by eye
+    #[arg(long)]
+    answer: Option<String>,
+}
+
+#[derive(Args, Debug)]
+struct NewDayArgs {
+    /// The day to create, e.g. `2025:day13`
+    day: DayId,
+
+    /// The example input to write to `data/test_data.txt`, or `-` to read it from stdin
+    #[arg(long)]
+    example: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+struct RunArgs {
+    /// Days to run, e.g. `2023:day5`, `2024:*` or `2022:10-15`. Runs every day when empty
+    selectors: Vec<Selector>,
+
+    /// Check every answer against the year's `answers.toml` instead of printing them
+    #[arg(long, conflicts_with = "time")]
+    check: bool,
+
+    /// Time parsing and each part instead of printing the answers
+    #[arg(long)]
+    time: bool,
+
+    /// How many days to run at once. Results are still reported in order
+    #[arg(
+        short,
+        long,
+        default_value_t = 1,
+        conflicts_with = "time",
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    jobs: u32,
+
+    /// How many times `--time` runs each day
+    #[arg(
+        long,
+        default_value_t = 1,
+        requires = "time",
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    runs: u32,
+
+    /// Also write the `--time` results to this file as JSON
+    #[arg(long, requires = "time")]
+    json: Option<PathBuf>,
+}
+
+fn select<'a>(all_days: &'a [DayEntry], selectors: &[Selector]) -> Vec<&'a DayEntry> {
+    all_days
+        .iter()
+        .filter(|entry| {
+            selectors.is_empty() || selectors.iter().any(|s| s.matches(entry.year, entry.day))
+        })
+        .collect()
+}
+
+/// Both parts' answers, calculated on whichever thread solved the day. A day that panics is
+/// reported as an error, so it doesn't hold up the days after it.
+fn solve(entry: &DayEntry) -> Result<Vec<(Part, Answer)>> {
+    let panicked = |message| eyre!("panicked: {}", message);
+    let solution = check::catch_panic(|| entry.build())
+        .map_err(panicked)?
+        .map_err(|e| eyre!("could not be loaded: {}", e))?;
+    check::catch_panic(|| {
+        Part::BOTH
+            .into_iter()
+            .map(|part| (part, solution.solve(part)))
+            .collect()
+    })
+    .map_err(panicked)
+}
+
+fn print_day(entry: &DayEntry, answers: Result<Vec<(Part, Answer)>>) {
+    match answers {
+        Ok(answers) => {
+            for (part, answer) in answers {
+                print_answer(&format!("{}{}", entry.name(), part), &answer);
+            }
+        }
+        Err(e) => eprintln!("{} {}", entry.name(), e),
+    }
+}
+
+fn check_days(days: &[&DayEntry], jobs: usize) -> Result<check::Summary> {
+    let mut ledgers: HashMap<usize, Ledger> = HashMap::new();
+    for entry in days {
+        if let Entry::Vacant(vacant) = ledgers.entry(entry.year) {
+            vacant.insert(Ledger::load(&entry.ledger_file)?);
+        }
+    }
+    let mut summary = check::Summary::default();
+    pool::run_in_order(
+        days,
+        jobs,
+        |entry| check::check_day(entry, &ledgers[&entry.year]),
+        |entry, day_check| {
+            check::print_day_check(&entry.name(), &day_check);
+            summary.add(&day_check);
+        },
+    );
+    Ok(summary)
+}
+
+fn time_days(days: &[&DayEntry], runs: usize) -> Vec<timing::DayTiming> {
+    let mut timings = vec![];
+    for entry in days {
+        match timing::time_day(entry, runs) {
+            Ok(timing) => timings.push(timing),
+            Err(e) => eprintln!("{} was not timed: {}", entry.name(), e),
+        }
+    }
+    timing::sort_slowest_first(&mut timings);
+    timings
+}
+
+fn fetch_days(days: &[&DayEntry], args: &FetchArgs) -> bool {
+    let mut client = args.server.client();
+    let cache = fetch::InputCache::new(&args.cache_dir);
+    let mut all_fetched = true;
+    for entry in days {
+        match fetch::fetch_day(entry, &mut client, &cache, args.refresh) {
+            Ok(outcome) => println!("{}: {}", entry.name(), outcome),
+            Err(e) => {
+                eprintln!("{} could not be fetched: {}", entry.name(), e);
+                all_fetched = false;
+            }
+        }
+    }
+    all_fetched
+}
+
+/// Returns whether the answer was correct
+fn submit_day(all_days: &[DayEntry], args: &SubmitArgs) -> Result<bool> {
+    let entry = all_days
+        .iter()
+        .find(|entry| entry.year == args.day.year && entry.day == args.day.day)
+        .ok_or_else(|| eyre!("{}:day{} isn't a known day", args.day.year, args.day.day))?;
+    let name = format!("{}{}", entry.name(), args.part);
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None => entry
+            .build()?
+            .answer(args.part)
+            .ok_or_else(|| eyre!("{} has no answer to submit", name))?,
+    };
+    if answer.contains('\n') {
+        bail!(
+            "{} is ascii art, so read it and pass what it says with --answer",
+            name
+        );
+    }
+    let attempts_file = entry.data_file("attempts.toml");
+    let mut attempts = attempts::Attempts::load(&attempts_file)?;
+    let outcome = submit::submit_answer(
+        &mut args.server.client(),
+        entry.year,
+        entry.day,
+        args.part,
+        &answer,
+        attempts.part(args.part),
+    )?;
+    match outcome {
+        submit::SubmitOutcome::NotSubmitted(rejection) => {
+            println!("{} of {} was not submitted: {}", name, answer, rejection);
+            Ok(false)
+        }
+        submit::SubmitOutcome::Submitted(verdict) => {
+            attempts.save(&attempts_file)?;
+            println!("{} of {} is {}", name, answer, verdict);
+            Ok(verdict == submit::Verdict::Correct)
+        }
+    }
+}
+
+fn new_day(args: &NewDayArgs) -> Result<()> {
+    let example = match &args.example {
+        Some(path) if path.as_os_str() == "-" => Some(std::io::read_to_string(std::io::stdin())?),
+        Some(path) => Some(std::fs::read_to_string(path)?),
+        None => None,
+    };
+    let day_dir = scaffold::new_day(
+        &scaffold::default_root(),
+        args.day.year,
+        args.day.day,
+        example.as_deref(),
+    )?;
+    println!("Created {}", day_dir.display());
+    Ok(())
+}
+
+fn run_days(days: &[&DayEntry], args: RunArgs) -> Result<()> {
+    if args.check || args.time {
+        // Panics are reported per day, so the default hook would only add noise
+        std::panic::set_hook(Box::new(|_| {}));
+    }
+    let jobs = args.jobs as usize;
+    if args.check {
+        let summary = check_days(days, jobs)?;
+        println!("{}", summary);
+        if !summary.is_success() {
+            std::process::exit(1);
+        }
+    } else if args.time {
+        let runs = args.runs as usize;
+        let timings = time_days(days, runs);
+        timing::print_table(&timings);
+        if let Some(path) = args.json {
+            let report = timing::Report {
+                runs,
+                days: &timings,
+            };
+            serde_json::to_writer_pretty(File::create(path)?, &report)?;
+        }
+    } else {
+        pool::run_in_order(
+            days,
+            jobs,
+            |entry| solve(entry),
+            |entry, answers| print_day(entry, answers),
+        );
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse();
+    let all_days = registry::all_days();
+    match cli.command {
+        Some(Command::Fetch(args)) => {
+            if !fetch_days(&select(&all_days, &args.selectors), &args) {
+                std::process::exit(1);
+            }
+        }
+        Some(Command::Submit(args)) => {
+            if !submit_day(&all_days, &args)? {
+                std::process::exit(1);
+            }
+        }
+        Some(Command::NewDay(args)) => new_day(&args)?,
+        None => run_days(&select(&all_days, &cli.run.selectors), cli.run)?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_reports_a_panicking_constructor() {
+        let entry = DayEntry::with_constructor(2023, 1, |_| panic!("bad input"));
+        let error = solve(&entry).unwrap_err();
+        assert_eq!("panicked: bad input", error.to_string());
+    }
+}
@@ -0,0 +1,77 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Runs `work` over every item on up to `jobs` threads. Each result is handed to `report` in
+/// the items' order, as soon as it and everything before it has finished, so the output is
+/// the same however many jobs there are. `work` shouldn't panic: a result that never arrives
+/// holds up every result after it.
+pub fn run_in_order<I, T>(
+    items: &[I],
+    jobs: usize,
+    work: impl Fn(&I) -> T + Sync,
+    mut report: impl FnMut(&I, T),
+) where
+    I: Sync,
+    T: Send,
+{
+    let next = AtomicUsize::new(0);
+    thread::scope(|scope| {
+        let (sender, receiver) = mpsc::channel();
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next, work) = (&next, &work);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                if index >= items.len() || sender.send((index, work(&items[index]))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+        let mut finished = BTreeMap::new();
+        let mut reported = 0;
+        for (index, result) in receiver {
+            finished.insert(index, result);
+            while let Some(result) = finished.remove(&reported) {
+                report(&items[reported], result);
+                reported += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn test_results_are_in_order() {
+        let items: Vec<u64> = (0..20).collect();
+        for jobs in [1, 4, 50] {
+            let mut results = vec![];
+            run_in_order(
+                &items,
+                jobs,
+                |&item| {
+                    // Earlier items finish last, so they'd arrive out of order
+                    thread::sleep(Duration::from_millis(20 - item));
+                    item * item
+                },
+                |&item, result| results.push((item, result)),
+            );
+            let expected: Vec<(u64, u64)> = items.iter().map(|&item| (item, item * item)).collect();
+            assert_eq!(expected, results);
+        }
+    }
+
+    #[test]
+    fn test_no_items() {
+        let mut reported = 0;
+        run_in_order(&[] as &[u64], 4, |&item| item, |_, _| reported += 1);
+        assert_eq!(0, reported);
+    }
+}
@@ -0,0 +1,197 @@
+use aoc_helpers::{AOCCalculator, Answer};
+use color_eyre::eyre::Result;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::A, Part::B];
+
+    /// How the site numbers the parts
+    pub fn level(&self) -> usize {
+        match self {
+            Part::A => 1,
+            Part::B => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Part, String> {
+        match s {
+            "a" => Ok(Part::A),
+            "b" => Ok(Part::B),
+            _ => Err(format!("expected a part of a or b, got {}", s)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::A => write!(f, "a"),
+            Part::B => write!(f, "b"),
+        }
+    }
+}
+
+/// The runner's view of a solved day, so it can work with answers without caring which year
+/// they came from
+pub trait Solution {
+    /// The answer for a part, `Answer::None` for parts that don't exist (e.g. day 25b)
+    fn solve(&self, part: Part) -> Answer;
+
+    /// The rendered answer for a part, or `None` for parts that don't exist
+    fn answer(&self, part: Part) -> Option<String> {
+        let answer = self.solve(part);
+        (answer != Answer::None).then(|| answer.to_string())
+    }
+}
+
+impl Solution for Box<dyn AOCCalculator> {
+    fn solve(&self, part: Part) -> Answer {
+        match part {
+            Part::A => self.part_a(),
+            Part::B => self.part_b(),
+        }
+    }
+}
+
+type Constructor = Box<dyn Fn(&str) -> Result<Box<dyn Solution>> + Send + Sync>;
+
+type YearDays<S, E> = [(usize, fn(&str) -> std::result::Result<S, E>)];
+
+pub struct DayEntry {
+    pub year: usize,
+    pub day: usize,
+    pub input_file: String,
+    pub ledger_file: String,
+    constructor: Constructor,
+}
+
+impl DayEntry {
+    pub fn name(&self) -> String {
+        format!("{}:day{}", self.year, self.day)
+    }
+
+    /// Another file in the day's `data` directory, next to its input
+    pub fn data_file(&self, name: &str) -> PathBuf {
+        PathBuf::from(&self.input_file).with_file_name(name)
+    }
+
+    /// A day that's built by `constructor` instead of from the registry
+    #[cfg(test)]
+    pub fn with_constructor(
+        year: usize,
+        day: usize,
+        constructor: impl Fn(&str) -> Result<Box<dyn Solution>> + Send + Sync + 'static,
+    ) -> DayEntry {
+        DayEntry {
+            year,
+            day,
+            input_file: String::new(),
+            ledger_file: String::new(),
+            constructor: Box::new(constructor),
+        }
+    }
+
+    /// Parses this day's input, ready for the answers to be calculated
+    pub fn build(&self) -> Result<Box<dyn Solution>> {
+        (self.constructor)(&self.input_file)
+    }
+}
+
+fn year_entries<S, E>(
+    year: usize,
+    days: &YearDays<S, E>,
+    input_file: fn(usize) -> String,
+    ledger_file: String,
+) -> Vec<DayEntry>
+where
+    S: Solution + 'static,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    days.iter()
+        .map(|&(day, constructor)| DayEntry {
+            year,
+            day,
+            input_file: input_file(day),
+            ledger_file: ledger_file.clone(),
+            constructor: Box::new(move |input_file| {
+                Ok(Box::new(constructor(input_file)?) as Box<dyn Solution>)
+            }),
+        })
+        .collect()
+}
+
+/// Every day of every year, ordered by year then day
+pub fn all_days() -> Vec<DayEntry> {
+    [
+        year_entries(
+            aoc_2022::YEAR,
+            &aoc_2022::DAYS,
+            aoc_2022::input_file,
+            aoc_2022::ledger_file(),
+        ),
+        year_entries(
+            aoc_2023::YEAR,
+            &aoc_2023::DAYS,
+            aoc_2023::input_file,
+            aoc_2023::ledger_file(),
+        ),
+        year_entries(
+            aoc_2024::YEAR,
+            &aoc_2024::DAYS,
+            aoc_2024::input_file,
+            aoc_2024::ledger_file(),
+        ),
+        year_entries(
+            aoc_2025::YEAR,
+            &aoc_2025::DAYS,
+            aoc_2025::input_file,
+            aoc_2025::ledger_file(),
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_all_days_are_ordered_and_unique() {
+        let keys: Vec<(usize, usize)> = all_days()
+            .iter()
+            .map(|entry| (entry.year, entry.day))
+            .collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted, keys);
+        assert_eq!(25 + 25 + 25 + 12, keys.len());
+    }
+
+    #[test]
+    fn test_input_file_is_within_the_year() {
+        let entry = all_days()
+            .into_iter()
+            .find(|entry| entry.year == 2023 && entry.day == 5)
+            .unwrap();
+        assert!(entry
+            .input_file
+            .ends_with("aoc_2023/day5/data/input_data.txt"));
+        assert_eq!("2023:day5", entry.name());
+    }
+}
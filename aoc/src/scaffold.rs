@@ -0,0 +1,265 @@
+use color_eyre::eyre::{bail, eyre, Result, WrapErr};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The directory every `aoc_YYYY` crate sits in
+pub fn default_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner is inside the repository")
+        .to_path_buf()
+}
+
+/// The skeleton files a day is made from. Anything else (lock files, inputs, build output) is
+/// left behind.
+const SKELETON_FILES: [&str; 4] = [
+    "Cargo.toml",
+    "src/lib.rs",
+    "src/parser.rs",
+    "data/test_data.txt",
+];
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).wrap_err_with(|| format!("reading {}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).wrap_err_with(|| format!("creating {}", parent.display()))?;
+    }
+    fs::write(path, contents).wrap_err_with(|| format!("writing {}", path.display()))
+}
+
+/// Turns a skeleton file into one for `day`: the `Skeleton` struct becomes `DayN`, the
+/// `skeleton_parser` becomes `dayN_parser`, and the package is named like the other days
+pub fn rename_skeleton(contents: &str, year: usize, day: usize) -> String {
+    contents
+        .replace(
+            "name = \"skeleton\"",
+            &format!("name = \"aoc_{}_day{}\"", year, day),
+        )
+        .replace("Skeleton", &format!("Day{}", day))
+        .replace("skeleton", &format!("day{}", day))
+}
+
+fn day_number(line: &str, prefix: &str) -> Option<usize> {
+    let rest = line.trim_start().strip_prefix(prefix)?;
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+/// Inserts `line` among the lines that start with `prefix` followed by a day number, keeping
+/// them ordered by `key` of the day
+fn insert_by_day<K: Ord>(
+    lines: &mut Vec<String>,
+    prefix: &str,
+    day: usize,
+    line: String,
+    key: impl Fn(usize) -> K,
+) -> Result<()> {
+    let days: Vec<(usize, usize)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, day_number(line, prefix)?)))
+        .collect();
+    if days.iter().any(|&(_, existing)| existing == day) {
+        bail!("day{} is already registered", day);
+    }
+    let index = match days.iter().find(|&&(_, existing)| key(existing) > key(day)) {
+        Some(&(index, _)) => index,
+        None => days.last().ok_or_else(|| eyre!("no days to go next to"))?.0 + 1,
+    };
+    lines.insert(index, line);
+    Ok(())
+}
+
+/// Adds the day to the year crate's dependencies, and to its workspace members if it lists
+/// them
+pub fn register_dependency(cargo_toml: &str, year: usize, day: usize) -> Result<String> {
+    let mut lines: Vec<String> = cargo_toml.lines().map(str::to_string).collect();
+    let dependency = format!(
+        "day{} = {{ path = \"day{}\", package = \"aoc_{}_day{}\" }}",
+        day, day, year, day
+    );
+    insert_by_day(&mut lines, "day", day, dependency, |day| day)?;
+    for line in lines.iter_mut() {
+        if let Some(members) = line
+            .strip_prefix("workspace = { members = [")
+            .and_then(|rest| rest.strip_suffix("] }"))
+        {
+            *line = format!("workspace = {{ members = [{}, \"day{}\"] }}", members, day);
+        }
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day to the year's `DAYS`, which is how the runner finds it
+pub fn register_day(lib_rs: &str, day: usize) -> Result<String> {
+    let mut lines: Vec<String> = lib_rs.lines().map(str::to_string).collect();
+    insert_by_day(
+        &mut lines,
+        "use day",
+        day,
+        format!("use day{}::Day{};", day, day),
+        // The way rustfmt orders imports, so day10 comes before day2
+        |day| day.to_string(),
+    )?;
+    insert_by_day(
+        &mut lines,
+        "(",
+        day,
+        format!("    ({}, boxed::<Day{}>),", day, day),
+        |day| day,
+    )?;
+    let length = lines
+        .iter_mut()
+        .find(|line| line.starts_with("pub const DAYS: [(usize, DayConstructor); "))
+        .ok_or_else(|| eyre!("couldn't find the DAYS list"))?;
+    let count = day_number(length, "pub const DAYS: [(usize, DayConstructor); ")
+        .ok_or_else(|| eyre!("couldn't read the length of DAYS"))?;
+    *length = format!(
+        "pub const DAYS: [(usize, DayConstructor); {}] = [",
+        count + 1
+    );
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates `aoc_YYYY/dayN` from the year's skeleton and registers it. `example`, if given,
+/// becomes the day's `data/test_data.txt`.
+pub fn new_day(root: &Path, year: usize, day: usize, example: Option<&str>) -> Result<PathBuf> {
+    let year_dir = root.join(format!("aoc_{}", year));
+    let skeleton = year_dir.join("skeleton");
+    let day_dir = year_dir.join(format!("day{}", day));
+    if !skeleton.exists() {
+        bail!("{} has no skeleton to copy", year_dir.display());
+    }
+    if day_dir.exists() {
+        bail!("{} already exists", day_dir.display());
+    }
+    let cargo_toml = year_dir.join("Cargo.toml");
+    let lib_rs = year_dir.join("src/lib.rs");
+    // Worked out before anything is written, so a failure leaves the year untouched
+    let registered_cargo_toml = register_dependency(&read(&cargo_toml)?, year, day)?;
+    let registered_lib_rs = register_day(&read(&lib_rs)?, day)?;
+
+    for file in SKELETON_FILES {
+        let contents = match (file, example) {
+            ("data/test_data.txt", Some(example)) => example.to_string(),
+            _ => rename_skeleton(&read(&skeleton.join(file))?, year, day),
+        };
+        write(&day_dir.join(file), &contents)?;
+    }
+    write(&cargo_toml, &registered_cargo_toml)?;
+    write(&lib_rs, &registered_lib_rs)?;
+    Ok(day_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const CARGO_TOML: &str = r#"workspace = { members = ["day1"] }
+[package]
+name = "aoc_2024"
+
+[dependencies]
+aoc_helpers = { path = "../aoc_helpers" }
+day1 = { path = "day1", package = "aoc_2024_day1" }
+day2 = { path = "day2", package = "aoc_2024_day2" }
+day10 = { path = "day10", package = "aoc_2024_day10" }
+"#;
+
+    const LIB_RS: &str = "pub use aoc_helpers::{AOCCalculator, AOCFileOrParseError, Answer};
+use day1::Day1;
+use day10::Day10;
+use day2::Day2;
+
+pub const DAYS: [(usize, DayConstructor); 3] = [
+    (1, boxed::<Day1>),
+    (2, boxed::<Day2>),
+    (10, boxed::<Day10>),
+];
+";
+
+    #[test]
+    fn test_rename_skeleton() {
+        let contents = "name = \"skeleton\"\npub struct Skeleton {}\nskeleton_parser::parse(input)\nlet skeleton = Skeleton::new(\"data/test_data.txt\")";
+        let expected = "name = \"aoc_2024_day3\"\npub struct Day3 {}\nday3_parser::parse(input)\nlet day3 = Day3::new(\"data/test_data.txt\")";
+        assert_eq!(expected, rename_skeleton(contents, 2024, 3));
+    }
+
+    #[test]
+    fn test_register_dependency() {
+        let expected = r#"workspace = { members = ["day1", "day3"] }
+[package]
+name = "aoc_2024"
+
+[dependencies]
+aoc_helpers = { path = "../aoc_helpers" }
+day1 = { path = "day1", package = "aoc_2024_day1" }
+day2 = { path = "day2", package = "aoc_2024_day2" }
+day3 = { path = "day3", package = "aoc_2024_day3" }
+day10 = { path = "day10", package = "aoc_2024_day10" }
+"#;
+        assert_eq!(expected, register_dependency(CARGO_TOML, 2024, 3).unwrap());
+        assert!(register_dependency(CARGO_TOML, 2024, 2).is_err());
+    }
+
+    #[test]
+    fn test_register_day() {
+        let expected = "pub use aoc_helpers::{AOCCalculator, AOCFileOrParseError, Answer};
+use day1::Day1;
+use day10::Day10;
+use day11::Day11;
+use day2::Day2;
+
+pub const DAYS: [(usize, DayConstructor); 4] = [
+    (1, boxed::<Day1>),
+    (2, boxed::<Day2>),
+    (10, boxed::<Day10>),
+    (11, boxed::<Day11>),
+];
+";
+        assert_eq!(expected, register_day(LIB_RS, 11).unwrap());
+        assert!(register_day(LIB_RS, 10).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let year_dir = root.join("aoc_2024");
+        write(&year_dir.join("Cargo.toml"), CARGO_TOML).unwrap();
+        write(&year_dir.join("src/lib.rs"), LIB_RS).unwrap();
+        for file in SKELETON_FILES {
+            let skeleton = default_root().join("aoc_2024/skeleton").join(file);
+            write(
+                &year_dir.join("skeleton").join(file),
+                &read(&skeleton).unwrap(),
+            )
+            .unwrap();
+        }
+
+        let day_dir = new_day(&root, 2024, 3, Some("1\n2\n3\n")).unwrap();
+        let lib = read(&day_dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day3 {"));
+        assert!(!lib.to_lowercase().contains("skeleton"));
+        let parser = read(&day_dir.join("src/parser.rs")).unwrap();
+        assert!(parser.contains("grammar day3_parser()"));
+        assert_eq!(
+            "1\n2\n3\n",
+            read(&day_dir.join("data/test_data.txt")).unwrap()
+        );
+        assert!(read(&year_dir.join("Cargo.toml"))
+            .unwrap()
+            .contains("day3 = { path = \"day3\", package = \"aoc_2024_day3\" }"));
+        assert!(read(&year_dir.join("src/lib.rs"))
+            .unwrap()
+            .contains("(3, boxed::<Day3>),"));
+
+        assert!(new_day(&root, 2024, 3, None).is_err());
+        assert!(new_day(&root, 2030, 1, None).is_err());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
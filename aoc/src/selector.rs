@@ -0,0 +1,129 @@
+extern crate peg;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Picks out days to run, written as `2023:day5`, `2024:*`, `2022:10-15` or just `2024`.
+/// Several days and ranges can be combined with commas, e.g. `2022:1,3,10-15`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    year: usize,
+    days: Option<Vec<RangeInclusive<usize>>>,
+}
+
+/// Exactly one day, written as `2023:day5` or `2023:5`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayId {
+    pub year: usize,
+    pub day: usize,
+}
+
+peg::parser! { pub grammar selector_parser() for str {
+    rule number() -> usize
+        = n:$(['0'..='9']+) {? n.parse().or(Err("number")) }
+    rule day() -> usize
+        = "day"? day:number() { day }
+    rule day_range() -> RangeInclusive<usize>
+        = start:day() "-" end:day() { start..=end }
+    rule single_day() -> RangeInclusive<usize>
+        = day:day() { day..=day }
+    rule days() -> Option<Vec<RangeInclusive<usize>>>
+        = "*" { None }
+        / days:(day_range() / single_day()) ++ "," { Some(days) }
+    pub rule parse() -> Selector
+        = year:number() days:(":" days:days() { days })? {
+            Selector { year, days: days.flatten() }
+        }
+    pub rule day_id() -> DayId
+        = year:number() ":" day:day() { DayId { year, day } }
+}}
+
+impl Selector {
+    pub fn matches(&self, year: usize, day: usize) -> bool {
+        self.year == year
+            && self
+                .days
+                .as_ref()
+                .map(|days| days.iter().any(|range| range.contains(&day)))
+                .unwrap_or(true)
+    }
+}
+
+impl FromStr for Selector {
+    type Err = peg::error::ParseError<peg::str::LineCol>;
+
+    fn from_str(s: &str) -> Result<Selector, Self::Err> {
+        selector_parser::parse(s)
+    }
+}
+
+impl FromStr for DayId {
+    type Err = peg::error::ParseError<peg::str::LineCol>;
+
+    fn from_str(s: &str) -> Result<DayId, Self::Err> {
+        selector_parser::day_id(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse_single_day() {
+        let expected = Selector {
+            year: 2023,
+            days: Some(vec![5..=5]),
+        };
+        assert_eq!(expected, "2023:day5".parse().unwrap());
+        assert_eq!(expected, "2023:5".parse().unwrap());
+    }
+
+    #[test]
+    fn test_parse_whole_year() {
+        let expected = Selector {
+            year: 2024,
+            days: None,
+        };
+        assert_eq!(expected, "2024:*".parse().unwrap());
+        assert_eq!(expected, "2024".parse().unwrap());
+    }
+
+    #[test]
+    fn test_parse_ranges() {
+        let expected = Selector {
+            year: 2022,
+            days: Some(vec![1..=1, 10..=15]),
+        };
+        assert_eq!(expected, "2022:1,10-15".parse().unwrap());
+        assert_eq!(expected, "2022:day1,day10-day15".parse().unwrap());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!("day5".parse::<Selector>().is_err());
+        assert!("2022:".parse::<Selector>().is_err());
+        assert!("2022:5-".parse::<Selector>().is_err());
+    }
+
+    #[test]
+    fn test_parse_day_id() {
+        let expected = DayId { year: 2024, day: 5 };
+        assert_eq!(expected, "2024:day5".parse().unwrap());
+        assert_eq!(expected, "2024:5".parse().unwrap());
+        assert!("2024:*".parse::<DayId>().is_err());
+        assert!("2024:1-5".parse::<DayId>().is_err());
+    }
+
+    #[test]
+    fn test_matches() {
+        let selector: Selector = "2022:1,10-15".parse().unwrap();
+        assert!(selector.matches(2022, 1));
+        assert!(selector.matches(2022, 12));
+        assert!(!selector.matches(2022, 2));
+        assert!(!selector.matches(2023, 1));
+        let selector: Selector = "2024:*".parse().unwrap();
+        assert!(selector.matches(2024, 25));
+        assert!(!selector.matches(2025, 1));
+    }
+}
@@ -0,0 +1,173 @@
+use crate::attempts::{PartAttempts, Rejection};
+use crate::client::Client;
+use crate::registry::Part;
+use color_eyre::eyre::Result;
+use std::fmt::Display;
+
+/// What the site made of a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Answered too soon after the last one, with how long is left to wait if the page said
+    RateLimited(Option<String>),
+    /// The part is either already solved or not unlocked yet
+    WrongLevel,
+    Unrecognized(String),
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::RateLimited(Some(wait)) => write!(f, "rate limited, {} left to wait", wait),
+            Verdict::RateLimited(None) => write!(f, "rate limited"),
+            Verdict::WrongLevel => write!(f, "already solved, or not unlocked yet"),
+            Verdict::Unrecognized(text) => write!(f, "unrecognized response: {}", text),
+        }
+    }
+}
+
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = text.find(start)? + start.len();
+    let to = from + text[from..].find(end)?;
+    Some(&text[from..to])
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub fn parse_response(page: &str) -> Verdict {
+    let text = strip_tags(between(page, "<article>", "</article>").unwrap_or(page));
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if text.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if text.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::RateLimited(between(&text, "You have ", " left to wait").map(str::to_string))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unrecognized(text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    /// The history already rules the answer out, so the site wasn't asked
+    NotSubmitted(Rejection),
+    Submitted(Verdict),
+}
+
+/// Submits an answer unless the history rules it out, recording the verdict in the history
+pub fn submit_answer(
+    client: &mut Client,
+    year: usize,
+    day: usize,
+    part: Part,
+    answer: &str,
+    attempts: &mut PartAttempts,
+) -> Result<SubmitOutcome> {
+    if let Some(rejection) = attempts.rejects(answer) {
+        return Ok(SubmitOutcome::NotSubmitted(rejection));
+    }
+    let page = client.answer(year, day, part.level(), answer)?;
+    let verdict = parse_response(&page);
+    attempts.record(answer, &verdict);
+    Ok(SubmitOutcome::Submitted(verdict))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::TestServer;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn test_parse_responses() {
+        let cases = [
+            (
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.",
+                Verdict::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.",
+                Verdict::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Verdict::TooLow,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+                Verdict::Wrong,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 39s left to wait. <a href=\"/2024/day/5\">[Return to Day 5]</a>",
+                Verdict::RateLimited(Some("39s".to_string())),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::WrongLevel,
+            ),
+            (
+                "Something <em>new</em>.",
+                Verdict::Unrecognized("Something new.".to_string()),
+            ),
+        ];
+        for (article, expected) in cases {
+            assert_eq!(expected, parse_response(&page(article)));
+        }
+    }
+
+    #[test]
+    fn test_submit_records_the_verdict() {
+        let server = TestServer::serve(vec![(
+            200,
+            page("That's not the right answer; your answer is too high."),
+        )]);
+        let mut client = Client::new(&server.base_url, "abc123", Duration::ZERO);
+        let mut attempts = PartAttempts::default();
+        let outcome =
+            submit_answer(&mut client, 2022, 11, Part::B, "13985281920", &mut attempts).unwrap();
+        assert_eq!(SubmitOutcome::Submitted(Verdict::TooHigh), outcome);
+        assert_eq!(Some(13985281920), attempts.too_high);
+        assert!(server.requests()[0].ends_with("level=2&answer=13985281920"));
+
+        // The site isn't asked again: there's no response left for it to give
+        let outcome =
+            submit_answer(&mut client, 2022, 11, Part::B, "20000000000", &mut attempts).unwrap();
+        assert_eq!(
+            SubmitOutcome::NotSubmitted(Rejection::TooHigh(13985281920)),
+            outcome
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+/// A stand-in for the Advent of Code site. Answers each connection with the next canned
+/// `(status, body)` response, and keeps every request it was sent.
+pub struct TestServer {
+    pub base_url: String,
+    handle: JoinHandle<Vec<String>>,
+}
+
+fn read_request(stream: &mut impl Read) -> String {
+    let mut reader = BufReader::new(stream);
+    let mut request = String::new();
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+            content_length = length.trim().parse().unwrap();
+        }
+        request.push_str(&line);
+        if line == "\r\n" || line.is_empty() {
+            break;
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).unwrap();
+    request.push_str(&String::from_utf8(body).unwrap());
+    request
+}
+
+impl TestServer {
+    pub fn serve(responses: Vec<(u16, String)>) -> TestServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                requests.push(read_request(&mut stream));
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Canned\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+        TestServer { base_url, handle }
+    }
+
+    /// Waits for every canned response to be used, then returns the requests that got them
+    pub fn requests(self) -> Vec<String> {
+        self.handle.join().unwrap()
+    }
+}
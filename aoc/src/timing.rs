@@ -0,0 +1,205 @@
+use crate::check::catch_panic;
+use crate::registry::{DayEntry, Part};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use std::cmp::Reverse;
+use std::time::{Duration, Instant};
+
+/// The spread of a phase's time over repeated runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Needs at least one sample. An even number of samples uses the mean of the middle two.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+        Stats {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+impl Serialize for Stats {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Stats", 3)?;
+        state.serialize_field("min_ms", &millis(self.min))?;
+        state.serialize_field("median_ms", &millis(self.median))?;
+        state.serialize_field("max_ms", &millis(self.max))?;
+        state.end()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayTiming {
+    pub year: usize,
+    pub day: usize,
+    /// Building the solution, i.e. reading and parsing the input
+    pub parse: Stats,
+    /// `None` for parts that don't exist
+    pub part_a: Option<Stats>,
+    pub part_b: Option<Stats>,
+    /// Parse plus both parts, per run
+    pub total: Stats,
+}
+
+impl DayTiming {
+    pub fn name(&self) -> String {
+        format!("{}:day{}", self.year, self.day)
+    }
+}
+
+/// Builds and solves a day `runs` times. Fails with the reason if the input can't be loaded or
+/// anything panics.
+pub fn time_day(entry: &DayEntry, runs: usize) -> Result<DayTiming, String> {
+    let mut parse = vec![];
+    let mut parts: [Vec<Duration>; 2] = [vec![], vec![]];
+    let mut totals = vec![];
+    for _ in 0..runs {
+        let start = Instant::now();
+        let solution = catch_panic(|| entry.build())?.map_err(|e| e.to_string())?;
+        let mut total = start.elapsed();
+        parse.push(total);
+        for (part, samples) in Part::BOTH.into_iter().zip(parts.iter_mut()) {
+            let start = Instant::now();
+            let answer = catch_panic(|| solution.answer(part))?;
+            let elapsed = start.elapsed();
+            if answer.is_some() {
+                samples.push(elapsed);
+                total += elapsed;
+            }
+        }
+        totals.push(total);
+    }
+    let [part_a, part_b] =
+        parts.map(|samples| (!samples.is_empty()).then(|| Stats::from_samples(&samples)));
+    Ok(DayTiming {
+        year: entry.year,
+        day: entry.day,
+        parse: Stats::from_samples(&parse),
+        part_a,
+        part_b,
+        total: Stats::from_samples(&totals),
+    })
+}
+
+/// Slowest days first, by median total time
+pub fn sort_slowest_first(timings: &mut [DayTiming]) {
+    timings.sort_by_key(|timing| (Reverse(timing.total.median), timing.year, timing.day));
+}
+
+fn format_median(stats: &Option<Stats>) -> String {
+    match stats {
+        Some(stats) => format!("{:.2?}", stats.median),
+        None => "-".to_string(),
+    }
+}
+
+pub fn print_table(timings: &[DayTiming]) {
+    println!(
+        "{:<14}{:>12}{:>12}{:>12}{:>12}{:>12}{:>12}",
+        "day", "parse", "part a", "part b", "total", "min", "max"
+    );
+    for timing in timings {
+        println!(
+            "{:<14}{:>12}{:>12}{:>12}{:>12}{:>12}{:>12}",
+            timing.name(),
+            format_median(&Some(timing.parse)),
+            format_median(&timing.part_a),
+            format_median(&timing.part_b),
+            format_median(&Some(timing.total)),
+            format!("{:.2?}", timing.total.min),
+            format!("{:.2?}", timing.total.max),
+        );
+    }
+}
+
+/// What `--json` writes, so timings can be compared across commits
+#[derive(Debug, Serialize)]
+pub struct Report<'a> {
+    pub runs: usize,
+    pub days: &'a [DayTiming],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    fn stats(millis: u64) -> Stats {
+        Stats::from_samples(&[ms(millis)])
+    }
+
+    fn timing(year: usize, day: usize, total: u64) -> DayTiming {
+        DayTiming {
+            year,
+            day,
+            parse: stats(1),
+            part_a: Some(stats(total - 1)),
+            part_b: None,
+            total: stats(total),
+        }
+    }
+
+    #[test]
+    fn test_stats_odd_samples() {
+        let expected = Stats {
+            min: ms(1),
+            median: ms(3),
+            max: ms(9),
+        };
+        assert_eq!(expected, Stats::from_samples(&[ms(9), ms(1), ms(3)]));
+    }
+
+    #[test]
+    fn test_stats_even_samples() {
+        let expected = Stats {
+            min: ms(2),
+            median: ms(5),
+            max: ms(8),
+        };
+        assert_eq!(expected, Stats::from_samples(&[ms(8), ms(2), ms(6), ms(4)]));
+    }
+
+    #[test]
+    fn test_sort_slowest_first() {
+        let mut timings = vec![timing(2022, 1, 5), timing(2023, 21, 50), timing(2022, 2, 5)];
+        sort_slowest_first(&mut timings);
+        let names: Vec<String> = timings.iter().map(|timing| timing.name()).collect();
+        assert_eq!(vec!["2023:day21", "2022:day1", "2022:day2"], names);
+    }
+
+    #[test]
+    fn test_report_json() {
+        let days = [timing(2022, 19, 3)];
+        let json = serde_json::to_value(Report {
+            runs: 1,
+            days: &days,
+        })
+        .unwrap();
+        assert_eq!(1, json["runs"]);
+        assert_eq!(19, json["days"][0]["day"]);
+        assert_eq!(3.0, json["days"][0]["total"]["median_ms"]);
+        assert_eq!(serde_json::Value::Null, json["days"][0]["part_b"]);
+    }
+}
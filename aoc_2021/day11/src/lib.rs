@@ -1,6 +1,5 @@
 mod octopus;
 mod octopusflash;
 mod parser;
-mod point;
 
 pub use octopusflash::OctopusFlashSetup;
@@ -1,12 +1,12 @@
-use crate::point::Point;
+use aoc_helpers::point2d::Point2D;
 #[derive(Clone, Debug)]
 pub struct Octopus {
     energy: usize,
-    location: Point,
+    location: Point2D,
 }
 
 impl Octopus {
-    pub fn new_at_location_with_energy(energy: usize, location: Point) -> Octopus {
+    pub fn new_at_location_with_energy(energy: usize, location: Point2D) -> Octopus {
         Octopus { energy, location }
     }
     pub fn bump_energy(&mut self) -> bool {
@@ -1,5 +1,5 @@
 use crate::octopus::Octopus;
-use crate::point::Point;
+use aoc_helpers::point2d::{Neighbours, Point2D};
 use itertools::Itertools;
 
 #[derive(Clone, Debug)]
@@ -12,30 +12,33 @@ pub fn from_octopi(octopi: [Octopus; 100]) -> OctopusFlashSetup {
 }
 
 impl OctopusFlashSetup {
-    fn get_points_iter() -> Box<dyn Iterator<Item = Point>> {
+    fn get_points_iter() -> Box<dyn Iterator<Item = Point2D>> {
         Box::new(
             (0..10)
                 .cartesian_product(0..10)
-                .map(|(y, x)| Point::new(x, y)),
+                .map(|(y, x)| Point2D::new(x, y)),
         )
     }
 
-    fn get_octopus_at_point(self: &mut OctopusFlashSetup, point: &Point) -> Option<&mut Octopus> {
-        if point.out_of_bounds() {
-            None
-        } else {
-            let index = point.get_as_index();
+    fn get_octopus_at_point(self: &mut OctopusFlashSetup, point: &Point2D) -> Option<&mut Octopus> {
+        if (0..10).contains(&point.x) && (0..10).contains(&point.y) {
+            let index = (point.y * 10 + point.x) as usize;
             Some(&mut self.octopi[index])
+        } else {
+            None
         }
     }
 
-    fn update_neighbours(self: &mut OctopusFlashSetup, location: &Point) {
-        for point in location.get_neighbours() {
+    fn update_neighbours(self: &mut OctopusFlashSetup, location: &Point2D) {
+        for point in location.get_8_neighbours() {
             self.increase_octopus_energy(&point);
         }
     }
 
-    fn bump_energy_for_octopus_at_location(self: &mut OctopusFlashSetup, location: &Point) -> bool {
+    fn bump_energy_for_octopus_at_location(
+        self: &mut OctopusFlashSetup,
+        location: &Point2D,
+    ) -> bool {
         if let Some(octopus) = self.get_octopus_at_point(location) {
             octopus.bump_energy()
         } else {
@@ -43,7 +46,7 @@ impl OctopusFlashSetup {
         }
     }
 
-    fn increase_octopus_energy(self: &mut OctopusFlashSetup, location: &Point) {
+    fn increase_octopus_energy(self: &mut OctopusFlashSetup, location: &Point2D) {
         let needs_to_flash = self.bump_energy_for_octopus_at_location(location);
         if needs_to_flash {
             self.update_neighbours(location);
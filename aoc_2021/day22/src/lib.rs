@@ -1,4 +1,3 @@
-mod cuboid;
 mod parser;
 mod volumestep;
 mod volumestepbuilder;
@@ -1,7 +1,7 @@
 extern crate peg;
-use crate::cuboid::Cuboid;
 use crate::volumestep::VolumeStep;
 use crate::VolumeStepBuilder;
+use aoc_helpers::aabox::AABox;
 
 peg::parser! { grammar day22_parser() for str {
     rule positive_number() -> isize
@@ -18,7 +18,7 @@ peg::parser! { grammar day22_parser() for str {
         = v:(volume_on() / volume_off()) { v }
     rule volume_step() -> VolumeStep
         = volume_type:volume_type() " x=" min_x:number() ".." max_x:number() ",y=" min_y:number() ".." max_y:number() ",z=" min_z:number() ".." max_z:number() {
-            VolumeStep{ volume_type, block: Cuboid::new( min_x, max_x, min_y, max_y, min_z, max_z ) }
+            VolumeStep{ volume_type, block: AABox::from_inclusive([min_x, min_y, min_z], [max_x, max_y, max_z]) }
         }
     pub rule parse() -> VolumeStepBuilder
         = volume_steps:volume_step() ++ "\n" "\n" * {
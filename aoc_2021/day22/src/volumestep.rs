@@ -1,6 +1,6 @@
-use crate::cuboid::Cuboid;
+use aoc_helpers::aabox::AABox;
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct VolumeStep {
     pub volume_type: bool,
-    pub block: Cuboid,
+    pub block: AABox<3>,
 }
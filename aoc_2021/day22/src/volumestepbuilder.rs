@@ -1,16 +1,14 @@
-use crate::cuboid::Cuboid;
 use crate::volumestep::VolumeStep;
+use aoc_helpers::aabox::{AABox, BoxSet};
 
 pub struct VolumeStepBuilder {
     pub volume_steps: Vec<VolumeStep>,
 }
 
 impl VolumeStepBuilder {
-    fn subtract_from_other(to_be_subtracted: &[Cuboid], to_subtract: &[Cuboid]) -> Vec<Cuboid> {
-        to_be_subtracted
-            .iter()
-            .flat_map(|vol_to_be| vol_to_be.repeatedly_subtract_from(to_subtract))
-            .collect::<Vec<Cuboid>>()
+    /// Part a only looks at the cubes within 50 of the origin
+    fn day_a_region() -> AABox<3> {
+        AABox::from_inclusive([-50; 3], [50; 3])
     }
 
     fn get_next_volume_step(&mut self) -> VolumeStep {
@@ -19,48 +17,41 @@ impl VolumeStepBuilder {
         ret
     }
 
-    fn run_one_step(&mut self, resulting_volumes: &[Cuboid], check_day_a: bool) -> Vec<Cuboid> {
+    fn run_one_step(&mut self, volumes: &mut BoxSet<3>, check_day_a: bool) {
         let step = self.get_next_volume_step();
-        if check_day_a && step.block.outside_day_a_limit() {
-            return resulting_volumes.to_vec();
+        if check_day_a && !VolumeStepBuilder::day_a_region().contains_box(&step.block) {
+            return;
         }
-        let mut resulting_volumes =
-            VolumeStepBuilder::subtract_from_other(resulting_volumes, &[step.block.clone()]);
         if step.volume_type {
-            resulting_volumes.push(step.block);
+            volumes.insert(step.block);
+        } else {
+            volumes.remove(&step.block);
         }
-        resulting_volumes
-    }
-
-    fn calculate_all_volumes_size(volumes: &[Cuboid]) -> usize {
-        volumes.iter().map(|v| v.calculate_volume()).sum::<usize>()
     }
 
-    fn run_steps(&mut self, check_day_a: bool) -> Vec<Cuboid> {
-        let mut resulting_volumes = vec![];
+    fn run_steps(&mut self, check_day_a: bool) -> BoxSet<3> {
+        let mut volumes = BoxSet::new();
         while !self.volume_steps.is_empty() {
-            resulting_volumes = self.run_one_step(&resulting_volumes, check_day_a);
+            self.run_one_step(&mut volumes, check_day_a);
         }
-        resulting_volumes
+        volumes
     }
 
     /// Calculate the part a response
     pub fn calculate_day_a(&mut self) -> usize {
-        let volumes = self.run_steps(true);
-        VolumeStepBuilder::calculate_all_volumes_size(&volumes)
+        self.run_steps(true).volume()
     }
 
     /// Calculate the part b response
     pub fn calculate_day_b(&mut self) -> usize {
-        let volumes = self.run_steps(false);
-        VolumeStepBuilder::calculate_all_volumes_size(&volumes)
+        self.run_steps(false).volume()
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::cuboid::Cuboid;
     use crate::volumestepbuilder::VolumeStepBuilder;
+    use aoc_helpers::aabox::{AABox, BoxSet};
 
     #[test]
     fn test_parse() {
@@ -69,12 +60,12 @@ mod test {
 
     #[test]
     fn test_subtract_single() {
-        let volume_a = Cuboid::new(1, 1, 1, 3, 1, 3);
-        let volume_b = Cuboid::new(1, 3, 1, 3, 1, 3);
-        let subtracted_volumes = VolumeStepBuilder::subtract_from_other(&[volume_b], &[volume_a]);
-        let new_volume: usize = subtracted_volumes
+        let volume_a = AABox::from_inclusive([1, 1, 1], [1, 3, 3]);
+        let volume_b = AABox::from_inclusive([1, 1, 1], [3, 3, 3]);
+        let new_volume: usize = volume_b
+            .subtract(&volume_a)
             .iter()
-            .map(|v| v.calculate_volume())
+            .map(|v| v.volume())
             .sum();
         assert_eq!(new_volume, 18);
     }
@@ -86,16 +77,14 @@ mod test {
         //
         // We should subtract both existing volumes from the new volume before adding, and
         // end up with the new size.
-        let volume_a = Cuboid::new(1, 1, 1, 3, 1, 3);
-        let volume_b = Cuboid::new(3, 3, 1, 3, 1, 3);
-        let volume_c = Cuboid::new(1, 3, 1, 3, 1, 3);
-        let subtracted_volumes =
-            VolumeStepBuilder::subtract_from_other(&[volume_c], &[volume_a, volume_b]);
-        let new_volume: usize = subtracted_volumes
-            .iter()
-            .map(|v| v.calculate_volume())
-            .sum();
-        assert_eq!(new_volume, 9);
+        let volume_a = AABox::from_inclusive([1, 1, 1], [1, 3, 3]);
+        let volume_b = AABox::from_inclusive([3, 1, 1], [3, 3, 3]);
+        let volume_c = AABox::from_inclusive([1, 1, 1], [3, 3, 3]);
+        let mut volumes = BoxSet::new();
+        volumes.insert(volume_c);
+        volumes.remove(&volume_a);
+        volumes.remove(&volume_b);
+        assert_eq!(volumes.volume(), 9);
     }
 
     #[test]
@@ -113,46 +102,47 @@ mod test {
     #[test]
     fn test_daya_larger_step_by_step() {
         let mut day22_setup = VolumeStepBuilder::new(include_str!("../test_data2.txt"));
-        let next = day22_setup.run_one_step(&[], true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 139590);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 210918);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 225476);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 328328);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 387734);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 420416);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 436132);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 478727);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 494759);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 494804);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 492164);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 534936);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 534936);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 567192,);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 567150);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 592167);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 588567);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 592902);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 590029);
-        let next = day22_setup.run_one_step(&next, true);
-        assert_eq!(VolumeStepBuilder::calculate_all_volumes_size(&next), 590784);
+        let mut volumes = BoxSet::new();
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 139590);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 210918);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 225476);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 328328);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 387734);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 420416);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 436132);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 478727);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 494759);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 494804);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 492164);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 534936);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 534936);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 567192,);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 567150);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 592167);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 588567);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 592902);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 590029);
+        day22_setup.run_one_step(&mut volumes, true);
+        assert_eq!(volumes.volume(), 590784);
     }
 
     #[test]
@@ -1,6 +1,6 @@
 mod parser;
 use crate::parser::parse_data;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 
 pub struct Day1 {
     calories: Vec<usize>,
@@ -16,9 +16,12 @@ impl AOCCalculator for Day1 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -13,11 +13,7 @@ peg::parser! { pub grammar day1_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<Vec<usize>>, AOCFileOrParseError> {
-    if let Ok(ret) = day1_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day1_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -1,7 +1,7 @@
 mod parser;
 mod pixel_map;
 use crate::parser::parse_data;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 use pixel_map::PixelMap;
 
 #[derive(Clone)]
@@ -16,10 +16,12 @@ impl AOCCalculator for Day10 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        let pixel_map = self.run_instructions();
-        println!("{}a answer is {:?}", name, pixel_map.day_a_result);
-        println!("{}b answer is:\n{}", name, pixel_map);
+    fn part_a(&self) -> Answer {
+        self.run_instructions().day_a_result.into()
+    }
+
+    fn part_b(&self) -> Answer {
+        Answer::ascii_art(&self.run_instructions().to_string())
     }
 }
 
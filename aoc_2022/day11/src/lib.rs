@@ -1,8 +1,8 @@
 mod monkey;
 mod parser;
 use aoc_helpers::hash_utils::HashVec;
-use aoc_helpers::modular_math::least_common_multiple;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::modular_math::least_common_multiple_of;
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 use monkey::Monkey;
 use parser::parse_data;
 
@@ -18,11 +18,12 @@ impl AOCCalculator for Day11 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        let mut obj = self.clone();
-        println!("{}a answer is {:?}", name, obj.calculate_day_a());
-        let mut obj = self.clone();
-        println!("{}b answer is {:?}", name, obj.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.clone().calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.clone().calculate_day_b().into()
     }
 }
 
@@ -78,11 +79,12 @@ impl Day11 {
     }
 
     fn get_shared_modulo(&self) -> usize {
-        let shared_modulo = self
+        let test_conditions = self
             .monkeys
             .iter()
-            .map(|monkey| monkey.test_condition)
-            .fold(1, least_common_multiple);
+            .map(|monkey| monkey.test_condition as u128);
+        let shared_modulo = least_common_multiple_of(test_conditions)
+            .expect("The shared modulo should fit") as usize;
         for monkey in self.monkeys.iter() {
             assert_eq!((shared_modulo % monkey.test_condition), 0);
         }
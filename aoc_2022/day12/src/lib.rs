@@ -1,67 +1,44 @@
 mod map_point;
 mod parser;
-use std::collections::VecDeque;
-
 use crate::map_point::{MapPoint, Point};
 use crate::parser::parse_data;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::search::bfs;
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 
 #[derive(Clone)]
 pub struct Day12 {
     map_points: Vec<Vec<MapPoint>>,
-    best_effort: Vec<Vec<Option<usize>>>,
 }
 
 impl AOCCalculator for Day12 {
     fn new(filename: &str) -> Result<Day12, AOCFileOrParseError> {
-        let map_points = parse_data(&read_input_file(filename)?)?;
-        let best_effort: Vec<Vec<Option<usize>>> = map_points
-            .iter()
-            .map(|v| v.iter().map(|_| None).collect())
-            .collect();
         Ok(Day12 {
-            map_points,
-            best_effort,
+            map_points: parse_data(&read_input_file(filename)?)?,
         })
     }
 
-    fn print_results(&self, name: &str) {
-        let mut obj = self.clone();
-        println!("{}a answer is {:?}", name, obj.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
 impl Day12 {
-    fn calculate_day_a(&mut self) -> usize {
+    fn calculate_day_a(&self) -> usize {
         self.find_end(&self.find_starter_point())
             .expect("A real answer")
     }
 
-    fn find_end(&mut self, starting_point: &Point) -> Option<usize> {
-        let mut points: VecDeque<(Point, usize)> =
-            VecDeque::from(vec![(starting_point.clone(), 0)]);
-        let mut current_best: Option<usize> = None;
-        while let Some((point, new_best)) = points.pop_front() {
-            if self.check_and_set_current_value(&point, new_best) {
-                if let Some(val) = self.get_value_at_point(&point) {
-                    if val == MapPoint::End {
-                        if let Some(best) = current_best {
-                            if new_best < best {
-                                current_best = Some(new_best);
-                            }
-                        } else {
-                            current_best = Some(new_best);
-                        }
-                        continue;
-                    }
-                }
-                for neighbour in self.find_valid_neighbours(&point) {
-                    points.push_back((neighbour.clone(), new_best + 1))
-                }
-            }
-        }
-        current_best
+    fn find_end(&self, starting_point: &Point) -> Option<usize> {
+        bfs(
+            starting_point.clone(),
+            |point| self.find_valid_neighbours(point),
+            |point| self.get_value_at_point(point) == Some(MapPoint::End),
+        )
+        .map(|result| result.cost)
     }
 
     fn get_value_at_point(&self, point: &Point) -> Option<MapPoint> {
@@ -100,28 +77,10 @@ impl Day12 {
         panic!("Did not find a point");
     }
 
-    fn check_and_set_current_value(&mut self, point: &Point, new_val: usize) -> bool {
-        let old = self.best_effort[point.y][point.x];
-        match (old, new_val) {
-            (None, new_val) => {
-                self.best_effort[point.y][point.x] = Some(new_val);
-                true
-            }
-            (Some(old_val), new_val) if new_val < old_val => {
-                self.best_effort[point.y][point.x] = Some(new_val);
-                true
-            }
-            _ => false,
-        }
-    }
-
     fn calculate_day_b(&self) -> usize {
         self.find_all_points_at_0()
             .iter()
-            .flat_map(|starting_point| {
-                let mut obj = self.clone();
-                obj.find_end(starting_point)
-            })
+            .flat_map(|starting_point| self.find_end(starting_point))
             .min()
             .unwrap()
     }
@@ -147,7 +106,7 @@ mod tests {
 
     #[test]
     fn test_calculate_day_a() {
-        let mut day12 = Day12::new("data/test_data.txt").unwrap();
+        let day12 = Day12::new("data/test_data.txt").unwrap();
         let expected = 31;
         let actual = day12.calculate_day_a();
         assert_eq!(expected, actual);
@@ -155,7 +114,7 @@ mod tests {
 
     #[test]
     fn test_real_data() {
-        let mut day12 = Day12::new("data/input_data.txt").unwrap();
+        let day12 = Day12::new("data/input_data.txt").unwrap();
         let actual = day12.calculate_day_a();
         // Worked out by borrowing someone else's code from reddit to check.
         // Note: First person I borrowed from had the same 470 that I do, so something with my
@@ -5,7 +5,7 @@ pub enum MapPoint {
     Other(u32),
 }
 
-#[derive(Debug, Eq, PartialEq, Clone)]
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
 pub struct Point {
     pub x: usize,
     pub y: usize,
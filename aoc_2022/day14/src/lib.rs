@@ -2,13 +2,12 @@ mod parser;
 use std::collections::HashSet;
 
 use crate::parser::parse_data;
-mod point;
 mod wall;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::direction::Direction8;
+use aoc_helpers::point2d::Point2D;
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 use wall::Walls;
 
-use self::point::Point;
-
 pub struct Day14 {
     walls: Walls,
 }
@@ -20,20 +19,33 @@ impl AOCCalculator for Day14 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
+/// Sand falls straight down if it can, otherwise diagonally to the left, then to the right
+fn get_next_contenders(point: &Point2D) -> [Point2D; 3] {
+    [
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::DownRight,
+    ]
+    .map(|direction| *point + direction.as_point())
+}
+
 impl Day14 {
     fn calculate_day_a(&self) -> usize {
-        let mut sands: HashSet<Point> = HashSet::new();
-        let mut last_path = vec![Point { x: 500, y: 0 }];
+        let mut sands: HashSet<Point2D> = HashSet::new();
+        let mut last_path = vec![Point2D { x: 500, y: 0 }];
         let max_point = self.walls.get_max_y();
         while let Some(destination) = self.drop_point_day_a(&last_path, &sands, max_point) {
             let last_entry = &destination[destination.len() - 1];
-            sands.insert(last_entry.clone());
+            sands.insert(*last_entry);
             last_path = destination;
         }
         sands.len()
@@ -41,18 +53,18 @@ impl Day14 {
 
     fn drop_point_day_a(
         &self,
-        previous_path: &[Point],
-        sands: &HashSet<Point>,
+        previous_path: &[Point2D],
+        sands: &HashSet<Point2D>,
         max_point: isize,
-    ) -> Option<Vec<Point>> {
-        let mut path: Vec<Point> = previous_path.to_vec();
+    ) -> Option<Vec<Point2D>> {
+        let mut path: Vec<Point2D> = previous_path.to_vec();
         while self
             .find_next_place_day_a(&path[path.len() - 1], sands)
             .is_none()
         {
             path.pop();
         }
-        while path[path.len() - 1].is_on_board(max_point) {
+        while path[path.len() - 1].y < max_point {
             if let Some(point) = self.find_next_place_day_a(&path[path.len() - 1], sands) {
                 path.push(point)
             } else {
@@ -62,22 +74,22 @@ impl Day14 {
         None
     }
 
-    fn find_next_place_day_a(&self, point: &Point, sands: &HashSet<Point>) -> Option<Point> {
-        for contender in point.get_next_contenders().iter() {
+    fn find_next_place_day_a(&self, point: &Point2D, sands: &HashSet<Point2D>) -> Option<Point2D> {
+        for contender in get_next_contenders(point).iter() {
             if !sands.contains(contender) && !self.walls.intersects_with(contender) {
-                return Some(contender.clone());
+                return Some(*contender);
             }
         }
         None
     }
 
     fn calculate_day_b(&self) -> usize {
-        let mut sands: HashSet<Point> = HashSet::new();
-        let mut last_path = vec![Point { x: 500, y: 0 }];
+        let mut sands: HashSet<Point2D> = HashSet::new();
+        let mut last_path = vec![Point2D { x: 500, y: 0 }];
         let max_point = self.walls.get_max_y();
         while let Some(destination) = self.drop_point_day_b(&last_path, &sands, max_point) {
             let last_entry = &destination[destination.len() - 1];
-            sands.insert(last_entry.clone());
+            sands.insert(*last_entry);
             last_path = destination;
         }
         sands.len() + 1 // include the starting point
@@ -85,11 +97,11 @@ impl Day14 {
 
     fn drop_point_day_b(
         &self,
-        previous_path: &[Point],
-        sands: &HashSet<Point>,
+        previous_path: &[Point2D],
+        sands: &HashSet<Point2D>,
         max_point: isize,
-    ) -> Option<Vec<Point>> {
-        let mut path: Vec<Point> = previous_path.to_vec();
+    ) -> Option<Vec<Point2D>> {
+        let mut path: Vec<Point2D> = previous_path.to_vec();
         while !path.is_empty()
             && self
                 .find_next_place_day_b(&path[path.len() - 1], sands, max_point)
@@ -114,16 +126,16 @@ impl Day14 {
 
     fn find_next_place_day_b(
         &self,
-        point: &Point,
-        sands: &HashSet<Point>,
+        point: &Point2D,
+        sands: &HashSet<Point2D>,
         max_point: isize,
-    ) -> Option<Point> {
-        for contender in point.get_next_contenders().iter() {
+    ) -> Option<Point2D> {
+        for contender in get_next_contenders(point).iter() {
             if !sands.contains(contender)
                 && !self.walls.intersects_with(contender)
                 && point.y < max_point + 1
             {
-                return Some(contender.clone());
+                return Some(*contender);
             }
         }
         None
@@ -1,6 +1,6 @@
 extern crate peg;
-use crate::point::Point;
 use crate::wall::Wall;
+use aoc_helpers::point2d::Point2D;
 use aoc_helpers::AOCFileOrParseError;
 
 peg::parser! { pub grammar day14_parser() for str {
@@ -10,8 +10,8 @@ peg::parser! { pub grammar day14_parser() for str {
         = "-" n:positive_number() { -n }
     rule number() -> isize
         = n:(negative_number() / positive_number()) { n }
-    rule point() -> Point
-        = x:number() "," y:number() { Point{x, y} }
+    rule point() -> Point2D
+        = x:number() "," y:number() { Point2D { x, y } }
     rule wall() -> Wall
         = points:point() ++ " -> " { Wall::new(&points) }
     pub rule parse() -> Vec<Wall>
@@ -21,11 +21,7 @@ peg::parser! { pub grammar day14_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<Wall>, AOCFileOrParseError> {
-    if let Ok(ret) = day14_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day14_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
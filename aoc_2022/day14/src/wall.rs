@@ -2,11 +2,11 @@ use std::collections::HashSet;
 
 use itertools::Itertools;
 
-use crate::point::Point;
+use aoc_helpers::point2d::Point2D;
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct Walls {
-    points: HashSet<Point>,
+    points: HashSet<Point2D>,
 }
 
 impl Walls {
@@ -22,7 +22,7 @@ impl Walls {
         Walls { points }
     }
 
-    pub fn intersects_with(&self, point: &Point) -> bool {
+    pub fn intersects_with(&self, point: &Point2D) -> bool {
         self.points.contains(point)
     }
 
@@ -38,19 +38,19 @@ pub struct Wall {
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct Edge {
-    pub start: Point,
-    pub end: Point,
+    pub start: Point2D,
+    pub end: Point2D,
 }
 
 impl Wall {
-    pub fn new(points: &[Point]) -> Wall {
+    pub fn new(points: &[Point2D]) -> Wall {
         if points.len() <= 1 {
             panic!("Must have at least 2 points for a wall");
         }
         let edges = (0..(points.len() - 1))
             .map(|i| Edge {
-                start: points[i].clone(),
-                end: points[i + 1].clone(),
+                start: points[i],
+                end: points[i + 1],
             })
             .collect();
         Wall { edges }
@@ -58,7 +58,7 @@ impl Wall {
 }
 
 impl Edge {
-    fn iter_points(&self) -> Box<dyn Iterator<Item = Point>> {
+    fn iter_points(&self) -> Box<dyn Iterator<Item = Point2D>> {
         let min_x = self.start.x.min(self.end.x);
         let max_x = self.start.x.max(self.end.x);
         let min_y = self.start.y.min(self.end.y);
@@ -66,7 +66,7 @@ impl Edge {
         Box::new(
             (min_x..=max_x)
                 .cartesian_product(min_y..=max_y)
-                .map(|(x, y)| Point { x, y }),
+                .map(|(x, y)| Point2D { x, y }),
         )
     }
 }
@@ -1,14 +1,13 @@
 mod parser;
-mod point;
-mod range;
 
 use crate::parser::parse_data;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
-use point::Point;
-use range::RangeCollection;
+use aoc_helpers::{
+    interval::IntervalSet, point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError,
+    Answer,
+};
 
 pub struct Day15 {
-    sensors: Vec<(Point, isize)>,
+    sensors: Vec<(Point2D, isize)>,
 }
 
 impl AOCCalculator for Day15 {
@@ -17,52 +16,75 @@ impl AOCCalculator for Day15 {
             sensors: parse_data(&read_input_file(filename)?)?
                 .iter()
                 .copied()
-                .map(|(p1, p2)| (p1, p1.manhattan_distance(&p2)))
+                .map(|(p1, p2)| (p1, p1.get_manhattan_distance(&p2) as isize))
                 .collect(),
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a(2000000));
-        println!("{}b answer is {:?}", name, self.calculate_day_b(4000000));
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a(2000000).into()
     }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b(4000000).into()
+    }
+}
+
+fn get_all_points_at_manhattan_distance(center: &Point2D, distance: isize) -> Vec<Point2D> {
+    let mut ret = Vec::with_capacity(distance as usize * 4);
+    for i in 0..(distance - 1) {
+        ret.push(Point2D {
+            x: center.x - distance + i,
+            y: center.y - i,
+        });
+        ret.push(Point2D {
+            x: center.x + i,
+            y: center.y - distance + i,
+        });
+        ret.push(Point2D {
+            x: center.x + distance - i,
+            y: center.y + i,
+        });
+        ret.push(Point2D {
+            x: center.x - i,
+            y: center.y + distance - i,
+        });
+    }
+    ret
 }
 
 impl Day15 {
     fn calculate_day_a(&self, y: isize) -> usize {
-        let mut ranges = RangeCollection::new();
+        let mut ranges = IntervalSet::new();
         for (sensor, distance) in self.sensors.iter() {
             let y_diff = (sensor.y - y).abs();
             let min = sensor.x - (distance - y_diff);
             let max = sensor.x + (distance - y_diff);
             if min < max {
-                ranges.add(min, max);
+                ranges.insert(min..max);
             }
         }
-        ranges.get_size()
+        ranges.len() as usize
     }
 
     fn calculate_day_b(&self, max_size: isize) -> isize {
         for (sensor, distance) in self.sensors.iter() {
-            for point in sensor
-                .get_all_points_at_manhattan_distance(distance + 1)
-                .iter()
-            {
-                if point.outside_max(max_size) {
+            for point in get_all_points_at_manhattan_distance(sensor, distance + 1).iter() {
+                if !(0..=max_size).contains(&point.x) || !(0..=max_size).contains(&point.y) {
                     continue;
                 }
                 if !self.would_be_invalid(point) {
-                    return point.calculate_tuning_frequency();
+                    return point.x * 4000000 + point.y;
                 }
             }
         }
         panic!("No point found")
     }
 
-    fn would_be_invalid(&self, point: &Point) -> bool {
+    fn would_be_invalid(&self, point: &Point2D) -> bool {
         self.sensors
             .iter()
-            .any(|(sensor, distance)| sensor.manhattan_distance(point) <= *distance)
+            .any(|(sensor, distance)| sensor.get_manhattan_distance(point) as isize <= *distance)
     }
 }
 
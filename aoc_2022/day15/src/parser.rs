@@ -1,5 +1,5 @@
 extern crate peg;
-use crate::point::Point;
+use aoc_helpers::point2d::Point2D;
 use aoc_helpers::AOCFileOrParseError;
 
 peg::parser! { pub grammar day15_parser() for str {
@@ -9,22 +9,18 @@ peg::parser! { pub grammar day15_parser() for str {
         = "-" n:positive_number() { -n }
     rule number() -> isize
         = n:(negative_number() / positive_number()) { n }
-    rule line() -> (Point, Point)
+    rule line() -> (Point2D, Point2D)
         = "Sensor at x=" x1:number() ", y=" y1:number() ": closest beacon is at x=" x2:number() ", y=" y2:number() {
-            (Point{x: x1, y: y1}, Point{x: x2, y: y2})
+            (Point2D{x: x1, y: y1}, Point2D{x: x2, y: y2})
         }
-    pub rule parse() -> Vec<(Point, Point)>
+    pub rule parse() -> Vec<(Point2D, Point2D)>
         = lines:line() ++ ("\n") "\n" * {
              { lines }
         }
 }}
 
-pub fn parse_data(input: &str) -> Result<Vec<(Point, Point)>, AOCFileOrParseError> {
-    if let Ok(ret) = day15_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+pub fn parse_data(input: &str) -> Result<Vec<(Point2D, Point2D)>, AOCFileOrParseError> {
+    day15_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -1,7 +1,7 @@
 mod parser;
 mod tunnel;
 use crate::parser::parse_data;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 use std::collections::VecDeque;
 
 use tunnel::Tunnels;
@@ -37,9 +37,12 @@ impl AOCCalculator for Day16 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 impl Day16 {
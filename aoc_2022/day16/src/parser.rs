@@ -20,11 +20,7 @@ peg::parser! { pub grammar day16_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Tunnels, AOCFileOrParseError> {
-    if let Ok(ret) = day16_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day16_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
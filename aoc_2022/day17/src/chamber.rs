@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 use crate::{shapes::Shape, types::Point};
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Chamber {
     width: usize,
     height_offset: usize,
@@ -73,6 +73,25 @@ impl Chamber {
         }
         panic!("No maximum height found")
     }
+
+    /// The top `depth` rows, from the highest down, with the floor counting as filled. Rocks
+    /// can't fall far below the top, so this is all that decides where the next ones land.
+    pub fn surface(&self, depth: usize) -> Vec<Vec<bool>> {
+        let height = self.get_maximum_height();
+        (height.saturating_sub(depth)..height)
+            .rev()
+            .map(|y| {
+                (0..self.width)
+                    .map(|x| {
+                        !self.is_location_free(&Point {
+                            x: x as isize,
+                            y: y as isize,
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
 }
 
 impl Display for Chamber {
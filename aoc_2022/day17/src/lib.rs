@@ -3,7 +3,9 @@ mod parser;
 mod shapes;
 mod types;
 use crate::parser::parse_data;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{
+    cycle::extrapolate, read_input_file, AOCCalculator, AOCFileOrParseError, Answer,
+};
 use types::Direction;
 
 use self::{chamber::Chamber, shapes::Shape, types::Point};
@@ -12,6 +14,15 @@ pub struct Day17 {
     directions: Vec<Direction>,
 }
 
+/// The chamber after some number of rocks have settled, with the next rock ready to fall
+#[derive(Clone)]
+struct Tower {
+    chamber: Chamber,
+    shape: Shape,
+    rocks: usize,
+    next_direction: usize,
+}
+
 impl AOCCalculator for Day17 {
     fn new(filename: &str) -> Result<Day17, AOCFileOrParseError> {
         Ok(Day17 {
@@ -19,9 +30,12 @@ impl AOCCalculator for Day17 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -30,50 +44,58 @@ impl Day17 {
         self.simulate_n_steps(2022)
     }
 
-    fn simulate_n_steps(&self, n: usize) -> usize {
-        let mut chamber = Chamber::new(7);
-        let mut shape = Shape::new(Point { x: 2, y: 3 });
-        let mut count = 0;
-        let mut shape_drop_count = 0;
-        let mut state_at_end: Vec<(i32, usize, Shape, usize)> = Vec::new();
-        let mut pseudo_height = 0;
+    fn drop_rock(&self, tower: &mut Tower) {
         let downward_displacement = Point { x: 0, y: -1 };
         loop {
-            for direction in self.directions.iter() {
-                if chamber.can_shape_move(&shape, &direction.get_as_displacement()) {
-                    shape.move_shape(&direction.get_as_displacement())
-                }
-                if chamber.can_shape_move(&shape, &downward_displacement) {
-                    shape_drop_count += 1;
-                    shape.move_shape(&downward_displacement);
-                } else {
-                    chamber.set_shape_down(&shape);
-                    count += 1;
-                    shape_drop_count = 0;
-                    if count == n {
-                        return chamber.get_maximum_height() + pseudo_height;
-                    }
-                    shape = shape.next_shape(Point {
-                        x: 2,
-                        y: 3 + chamber.get_maximum_height() as isize,
-                    });
-                }
+            let direction = self.directions[tower.next_direction];
+            tower.next_direction = (tower.next_direction + 1) % self.directions.len();
+            if tower
+                .chamber
+                .can_shape_move(&tower.shape, &direction.get_as_displacement())
+            {
+                tower.shape.move_shape(&direction.get_as_displacement())
             }
-            for (old_drop_count, old_count, old_shape, old_height) in state_at_end.iter() {
-                if *old_drop_count == shape_drop_count && old_shape.is_same_shape_type(&shape) {
-                    let height_diff = chamber.get_maximum_height() - old_height;
-                    let count_diff = count - old_count;
-                    let counts_needed = n - count;
-                    let pseudo_iterations = counts_needed / count_diff;
-                    count += pseudo_iterations * count_diff;
-                    pseudo_height += pseudo_iterations * height_diff;
-                    break;
-                }
+            if tower
+                .chamber
+                .can_shape_move(&tower.shape, &downward_displacement)
+            {
+                tower.shape.move_shape(&downward_displacement);
+            } else {
+                tower.chamber.set_shape_down(&tower.shape);
+                tower.rocks += 1;
+                tower.shape = tower.shape.next_shape(Point {
+                    x: 2,
+                    y: 3 + tower.chamber.get_maximum_height() as isize,
+                });
+                return;
             }
-            state_at_end.push((shape_drop_count, count, shape, chamber.get_maximum_height()));
         }
     }
 
+    fn simulate_n_steps(&self, n: usize) -> usize {
+        let tower = Tower {
+            chamber: Chamber::new(7),
+            shape: Shape::new(Point { x: 2, y: 3 }),
+            rocks: 0,
+            next_direction: 0,
+        };
+        // Which rock and jet come next, and what the top of the tower looks like, decide
+        // everything after, so the towers repeat once those do
+        extrapolate(
+            &tower,
+            |tower| self.drop_rock(tower),
+            |tower| {
+                (
+                    tower.rocks % 5,
+                    tower.next_direction,
+                    tower.chamber.surface(30),
+                )
+            },
+            |tower| tower.chamber.get_maximum_height() as i64,
+            n,
+        ) as usize
+    }
+
     fn calculate_day_b(&self) -> usize {
         self.simulate_n_steps(1000000000000)
     }
@@ -18,11 +18,7 @@ peg::parser! { pub grammar day17_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<Direction>, AOCFileOrParseError> {
-    if let Ok(ret) = day17_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day17_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -199,15 +199,4 @@ impl Shape {
             Shape::Square(inner) => inner.location = &inner.location + displacement,
         }
     }
-
-    pub fn is_same_shape_type(&self, other: &Shape) -> bool {
-        matches!(
-            (self, other),
-            (Shape::Bar(_), Shape::Bar(_))
-                | (Shape::Plus(_), Shape::Plus(_))
-                | (Shape::Corner(_), Shape::Corner(_))
-                | (Shape::Pipe(_), Shape::Pipe(_))
-                | (Shape::Square(_), Shape::Square(_))
-        )
-    }
 }
@@ -2,7 +2,7 @@ mod droplet;
 mod parser;
 use crate::parser::parse_data;
 use aoc_helpers::hash_utils::FromVec;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 use std::collections::{HashSet, VecDeque};
 
 use self::droplet::Droplet;
@@ -23,10 +23,12 @@ impl AOCCalculator for Day18 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        let mut obj = self.clone();
-        println!("{}b answer is {:?}", name, obj.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.clone().calculate_day_b().into()
     }
 }
 
@@ -14,11 +14,7 @@ peg::parser! { pub grammar day18_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<Droplet>, AOCFileOrParseError> {
-    if let Ok(ret) = day18_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day18_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
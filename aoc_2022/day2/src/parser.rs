@@ -20,11 +20,7 @@ peg::parser! { pub grammar day2_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<(Hand, Hand)>, AOCFileOrParseError> {
-    if let Ok(ret) = day2_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day2_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
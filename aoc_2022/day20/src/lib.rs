@@ -2,7 +2,7 @@ mod parser;
 use std::fmt::Display;
 
 use crate::parser::parse_data;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 
 #[derive(Clone)]
 pub struct Day20 {
@@ -28,11 +28,12 @@ impl AOCCalculator for Day20 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        let mut obj = self.clone();
-        println!("{}a answer is {:?}", name, obj.calculate_day_a());
-        let mut obj = self.clone();
-        println!("{}b answer is {:?}", name, obj.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.clone().calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.clone().calculate_day_b().into()
     }
 }
 
@@ -2,7 +2,7 @@ mod parser;
 use std::collections::HashMap;
 
 use crate::parser::parse_data;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{dag::Dag, read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 
 use self::types::Operation;
 mod types;
@@ -10,47 +10,66 @@ mod types;
 #[derive(Clone)]
 pub struct Day21 {
     monkeys: HashMap<String, Operation>,
+    /// Every monkey after the monkeys it needs the numbers of
+    evaluation_order: Vec<String>,
 }
 
 impl AOCCalculator for Day21 {
     fn new(filename: &str) -> Result<Day21, AOCFileOrParseError> {
+        let monkeys: HashMap<String, Operation> = parse_data(&read_input_file(filename)?)?;
+        let dependencies = monkeys.iter().flat_map(|(name, operation)| {
+            operation
+                .operands()
+                .into_iter()
+                .map(|operand| (operand.clone(), name.clone()))
+        });
+        let evaluation_order = Dag::new(monkeys.keys().cloned(), dependencies)
+            .map_err(|cycle| {
+                AOCFileOrParseError::InvalidInput(format!(
+                    "monkeys are waiting on each other in a loop: {:?}",
+                    cycle.nodes
+                ))
+            })?
+            .topological_order()
+            .to_vec();
         Ok(Day21 {
-            monkeys: parse_data(&read_input_file(filename)?)?,
+            monkeys,
+            evaluation_order,
         })
     }
 
-    fn print_results(&self, name: &str) {
-        let mut obj = self.clone();
-        println!("{}a answer is {:?}", name, obj.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
 impl Day21 {
-    fn calculate_day_a(&mut self) -> usize {
-        self.get_value_at("root") as usize
+    fn calculate_day_a(&self) -> usize {
+        self.get_value_at(&self.monkeys, "root") as usize
     }
 
-    fn get_value_at(&mut self, name: &str) -> isize {
-        let monkey = self.monkeys.get(name).unwrap().clone();
-        let ret = match monkey {
-            Operation::Literal(val) => val as isize,
-            Operation::Add(left, right) => self.get_value_at(&left) + self.get_value_at(&right),
-            Operation::Subtract(left, right) => {
-                self.get_value_at(&left) - self.get_value_at(&right)
-            }
-            Operation::Multiply(left, right) => {
-                self.get_value_at(&left) * self.get_value_at(&right)
-            }
-            Operation::Divide(left, right) => self.get_value_at(&left) / self.get_value_at(&right),
-        };
-        self.monkeys
-            .insert(name.to_string(), Operation::Literal(ret));
-        ret
+    fn get_value_at(&self, monkeys: &HashMap<String, Operation>, name: &str) -> isize {
+        let mut values: HashMap<&str, isize> = HashMap::new();
+        for monkey in self.evaluation_order.iter() {
+            let value = |operand: &String| values[operand.as_str()];
+            let result = match &monkeys[monkey] {
+                Operation::Literal(val) => *val,
+                Operation::Add(left, right) => value(left) + value(right),
+                Operation::Subtract(left, right) => value(left) - value(right),
+                Operation::Multiply(left, right) => value(left) * value(right),
+                Operation::Divide(left, right) => value(left) / value(right),
+            };
+            values.insert(monkey, result);
+        }
+        values[name]
     }
 
     fn get_diff_at(&self, human_value: isize) -> isize {
-        let mut obj = self.clone();
+        let mut monkeys = self.monkeys.clone();
         let root_monkey = self.monkeys.get("root").unwrap();
         let (left, right) = match root_monkey {
             Operation::Add(left, right) => (left, right),
@@ -59,13 +78,12 @@ impl Day21 {
             Operation::Multiply(left, right) => (left, right),
             _ => panic!("Should be +-*/"),
         };
-        obj.monkeys
-            .insert("humn".to_string(), Operation::Literal(human_value));
-        obj.monkeys.insert(
+        monkeys.insert("humn".to_string(), Operation::Literal(human_value));
+        monkeys.insert(
             "root".to_string(),
             Operation::Subtract(left.to_string(), right.to_string()),
         );
-        obj.get_value_at("root")
+        self.get_value_at(&monkeys, "root")
     }
 
     fn calculate_day_b(&self) -> usize {
@@ -99,7 +117,7 @@ mod tests {
 
     #[test]
     fn test_calculate_day_a() {
-        let mut day21 = Day21::new("data/test_data.txt").unwrap();
+        let day21 = Day21::new("data/test_data.txt").unwrap();
         let expected = 152;
         let actual = day21.calculate_day_a();
         assert_eq!(expected, actual);
@@ -115,7 +133,7 @@ mod tests {
 
     #[test]
     fn test_real_input_calculate_day_a() {
-        let mut day21 = Day21::new("data/input_data.txt").unwrap();
+        let day21 = Day21::new("data/input_data.txt").unwrap();
         let expected = 56490240862410;
         let actual = day21.calculate_day_a();
         assert_eq!(expected, actual);
@@ -6,3 +6,16 @@ pub enum Operation {
     Divide(String, String),
     Literal(isize),
 }
+
+impl Operation {
+    /// The monkeys whose numbers this one needs
+    pub fn operands(&self) -> Vec<&String> {
+        match self {
+            Operation::Add(left, right)
+            | Operation::Subtract(left, right)
+            | Operation::Multiply(left, right)
+            | Operation::Divide(left, right) => vec![left, right],
+            Operation::Literal(_) => vec![],
+        }
+    }
+}
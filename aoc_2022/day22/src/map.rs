@@ -1,6 +1,6 @@
-use std::ops::Add;
-
 use crate::instruction::Instruction;
+use aoc_helpers::direction::Direction4;
+use aoc_helpers::point2d::Point2D;
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub enum MapTile {
@@ -35,7 +35,7 @@ impl Map {
         }
     }
 
-    fn get_at_point(&self, point: &Point) -> MapTile {
+    fn get_at_point(&self, point: &Point2D) -> MapTile {
         if (0..self.height).contains(&point.y)
             && (0..(self.points[point.y as usize].len() as isize)).contains(&point.x)
         {
@@ -47,7 +47,7 @@ impl Map {
 
     fn get_min_x(&self, y: isize) -> isize {
         for x in 0..self.width {
-            let point = Point { x, y };
+            let point = Point2D { x, y };
             if !self.get_at_point(&point).is_wrap() {
                 return x;
             }
@@ -57,7 +57,7 @@ impl Map {
 
     fn get_max_x(&self, y: isize) -> isize {
         for x in (0..self.width).rev() {
-            let point = Point { x, y };
+            let point = Point2D { x, y };
             if !self.get_at_point(&point).is_wrap() {
                 return x;
             }
@@ -67,7 +67,7 @@ impl Map {
 
     fn get_min_y(&self, x: isize) -> isize {
         for y in 0..self.height {
-            let point = Point { x, y };
+            let point = Point2D { x, y };
             if !self.get_at_point(&point).is_wrap() {
                 return y;
             }
@@ -77,7 +77,7 @@ impl Map {
 
     fn get_max_y(&self, x: isize) -> isize {
         for y in (0..self.height).rev() {
-            let point = Point { x, y };
+            let point = Point2D { x, y };
             if !self.get_at_point(&point).is_wrap() {
                 return y;
             }
@@ -85,21 +85,21 @@ impl Map {
         panic!("no max y found");
     }
 
-    pub fn get_mapped_point_day_a(&self, point: &Point, direction: &Direction) -> Point {
+    pub fn get_mapped_point_day_a(&self, point: &Point2D, direction: &Direction4) -> Point2D {
         match direction {
-            Direction::Up => Point {
+            Direction4::Up => Point2D {
                 x: point.x,
                 y: self.get_max_y(point.x),
             },
-            Direction::Right => Point {
+            Direction4::Right => Point2D {
                 x: self.get_min_x(point.y),
                 y: point.y,
             },
-            Direction::Down => Point {
+            Direction4::Down => Point2D {
                 x: point.x,
                 y: self.get_min_y(point.x),
             },
-            Direction::Left => Point {
+            Direction4::Left => Point2D {
                 x: self.get_max_x(point.y),
                 y: point.y,
             },
@@ -108,76 +108,33 @@ impl Map {
 
     pub fn get_starting_position(&self) -> Position {
         Position {
-            coord: Point {
+            coord: Point2D {
                 x: self.get_min_x(0),
                 y: 0,
             },
-            direction: Direction::Right,
+            direction: Direction4::Right,
         }
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
-pub struct Point {
-    pub x: isize,
-    pub y: isize,
-}
-
-#[derive(Debug, Eq, PartialEq, Copy, Clone)]
-pub enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
-impl Direction {
-    fn rotate_left(&self) -> Direction {
-        match self {
-            Direction::Up => Direction::Left,
-            Direction::Left => Direction::Down,
-            Direction::Down => Direction::Right,
-            Direction::Right => Direction::Up,
-        }
-    }
-
-    fn rotate_right(&self) -> Direction {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Left => Direction::Up,
-            Direction::Down => Direction::Left,
-            Direction::Right => Direction::Down,
-        }
-    }
-
-    fn as_coordinate(&self) -> Point {
-        match self {
-            Direction::Up => Point { x: 0, y: -1 },
-            Direction::Left => Point { x: -1, y: 0 },
-            Direction::Down => Point { x: 0, y: 1 },
-            Direction::Right => Point { x: 1, y: 0 },
-        }
-    }
-
-    fn get_password(&self) -> usize {
-        match self {
-            Direction::Up => 3,
-            Direction::Left => 2,
-            Direction::Down => 1,
-            Direction::Right => 0,
-        }
+fn facing_password(direction: &Direction4) -> usize {
+    match direction {
+        Direction4::Up => 3,
+        Direction4::Left => 2,
+        Direction4::Down => 1,
+        Direction4::Right => 0,
     }
 }
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub struct Position {
-    pub coord: Point,
-    pub direction: Direction,
+    pub coord: Point2D,
+    pub direction: Direction4,
 }
 
 impl Position {
     fn can_move_on_map(&self, map: &Map) -> bool {
-        let new_position = &self.coord + &self.direction.as_coordinate();
+        let new_position = self.coord + self.direction.as_point();
         let mut val = map.get_at_point(&new_position);
         if val.is_wrap() {
             let new_position = map.get_mapped_point_day_a(&new_position, &self.direction);
@@ -188,7 +145,7 @@ impl Position {
 
     fn move_forward_one_day_a(&mut self, map: &Map) {
         if self.can_move_on_map(map) {
-            self.coord = &self.coord + &self.direction.as_coordinate();
+            self.coord += self.direction.as_point();
             if map.get_at_point(&self.coord).is_wrap() {
                 self.coord = map.get_mapped_point_day_a(&self.coord, &self.direction);
             }
@@ -211,146 +168,146 @@ impl Position {
         }
     }
 
-    fn get_next_point_day_b(&mut self) -> (Point, Direction) {
+    fn get_next_point_day_b(&mut self) -> (Point2D, Direction4) {
         match (self.coord, self.direction) {
-            (coord, Direction::Up) if coord.is_edge_a() => {
+            (coord, Direction4::Up) if coord.is_edge_a() => {
                 let delta = coord.x - 50;
                 (
-                    Point {
+                    Point2D {
                         x: 0,
                         y: 150 + delta,
                     },
-                    Direction::Right,
+                    Direction4::Right,
                 )
             }
-            (coord, Direction::Up) if coord.is_edge_b() => {
+            (coord, Direction4::Up) if coord.is_edge_b() => {
                 let delta = coord.x - 100;
                 // Pretty sure we don't flip x here
-                (Point { x: delta, y: 199 }, Direction::Up)
+                (Point2D { x: delta, y: 199 }, Direction4::Up)
             }
-            (coord, Direction::Right) if coord.is_edge_c() => {
+            (coord, Direction4::Right) if coord.is_edge_c() => {
                 let delta = coord.y;
                 (
-                    Point {
+                    Point2D {
                         x: 99,
                         y: 149 - delta,
                     },
-                    Direction::Left,
+                    Direction4::Left,
                 )
             }
-            (coord, Direction::Left) if coord.is_edge_d() => {
+            (coord, Direction4::Left) if coord.is_edge_d() => {
                 let delta = coord.y;
                 (
-                    Point {
+                    Point2D {
                         x: 0,
                         y: 149 - delta,
                     },
-                    Direction::Right,
+                    Direction4::Right,
                 )
             }
-            (coord, Direction::Left) if coord.is_edge_e() => {
+            (coord, Direction4::Left) if coord.is_edge_e() => {
                 let delta = coord.y - 50;
-                (Point { x: delta, y: 100 }, Direction::Down)
+                (Point2D { x: delta, y: 100 }, Direction4::Down)
             }
-            (coord, Direction::Right) if coord.is_edge_f() => {
+            (coord, Direction4::Right) if coord.is_edge_f() => {
                 let delta = coord.y - 50;
                 (
-                    Point {
+                    Point2D {
                         x: 100 + delta,
                         y: 49,
                     },
-                    Direction::Up,
+                    Direction4::Up,
                 )
             }
-            (coord, Direction::Up) if coord.is_edge_g() => {
+            (coord, Direction4::Up) if coord.is_edge_g() => {
                 let delta = coord.x;
                 (
-                    Point {
+                    Point2D {
                         x: 50,
                         y: 50 + delta,
                     },
-                    Direction::Right,
+                    Direction4::Right,
                 )
             }
-            (coord, Direction::Left) if coord.is_edge_h() => {
+            (coord, Direction4::Left) if coord.is_edge_h() => {
                 let delta = coord.y - 100;
                 (
-                    Point {
+                    Point2D {
                         x: 50,
                         y: 49 - delta,
                     },
-                    Direction::Right,
+                    Direction4::Right,
                 )
             }
-            (coord, Direction::Right) if coord.is_edge_i() => {
+            (coord, Direction4::Right) if coord.is_edge_i() => {
                 let delta = coord.y - 100;
                 (
-                    Point {
+                    Point2D {
                         x: 149,
                         y: 49 - delta,
                     },
-                    Direction::Left,
+                    Direction4::Left,
                 )
             }
-            (coord, Direction::Down) if coord.is_edge_j() => {
+            (coord, Direction4::Down) if coord.is_edge_j() => {
                 let delta = coord.x - 50;
                 (
-                    Point {
+                    Point2D {
                         x: 49,
                         y: 150 + delta,
                     },
-                    Direction::Left,
+                    Direction4::Left,
                 )
             }
-            (coord, Direction::Left) if coord.is_edge_k() => {
+            (coord, Direction4::Left) if coord.is_edge_k() => {
                 let delta = coord.y - 150;
                 (
-                    Point {
+                    Point2D {
                         x: 50 + delta,
                         y: 0,
                     },
-                    Direction::Down,
+                    Direction4::Down,
                 )
             }
-            (coord, Direction::Right) if coord.is_edge_l() => {
+            (coord, Direction4::Right) if coord.is_edge_l() => {
                 let delta = coord.y - 150;
                 (
-                    Point {
+                    Point2D {
                         x: 50 + delta,
                         y: 149,
                     },
-                    Direction::Up,
+                    Direction4::Up,
                 )
             }
-            (coord, Direction::Down) if coord.is_edge_m() => {
+            (coord, Direction4::Down) if coord.is_edge_m() => {
                 let delta = coord.x;
                 // Pretty sure we don't flip this
                 (
-                    Point {
+                    Point2D {
                         x: 100 + delta,
                         y: 0,
                     },
-                    Direction::Down,
+                    Direction4::Down,
                 )
             }
-            (coord, Direction::Down) if coord.is_edge_n() => {
+            (coord, Direction4::Down) if coord.is_edge_n() => {
                 let delta = coord.x - 100;
                 (
-                    Point {
+                    Point2D {
                         x: 99,
                         y: 50 + delta,
                     },
-                    Direction::Left,
+                    Direction4::Left,
                 )
             }
-            (coord, direction) => (&coord + &direction.as_coordinate(), direction),
+            (coord, direction) => (coord + direction.as_point(), direction),
         }
     }
 
     pub fn run_instruction(&mut self, map: &Map, instruction: &Instruction, is_day_b: bool) {
         match instruction {
-            Instruction::RotateLeft => self.direction = self.direction.rotate_left(),
-            Instruction::RotateRight => self.direction = self.direction.rotate_right(),
+            Instruction::RotateLeft => self.direction = self.direction.turn_left(),
+            Instruction::RotateRight => self.direction = self.direction.turn_right(),
             Instruction::MoveForward(n) => {
                 for _ in 0..*n {
                     if is_day_b {
@@ -365,11 +322,28 @@ impl Position {
 
     pub fn get_password(&self) -> usize {
         ((1000 * (self.coord.y + 1) + 4 * (self.coord.x + 1)) as usize)
-            + self.direction.get_password()
+            + facing_password(&self.direction)
     }
 }
 
-impl Point {
+trait CubeEdges {
+    fn is_edge_a(&self) -> bool;
+    fn is_edge_b(&self) -> bool;
+    fn is_edge_c(&self) -> bool;
+    fn is_edge_d(&self) -> bool;
+    fn is_edge_e(&self) -> bool;
+    fn is_edge_f(&self) -> bool;
+    fn is_edge_g(&self) -> bool;
+    fn is_edge_h(&self) -> bool;
+    fn is_edge_i(&self) -> bool;
+    fn is_edge_j(&self) -> bool;
+    fn is_edge_k(&self) -> bool;
+    fn is_edge_l(&self) -> bool;
+    fn is_edge_m(&self) -> bool;
+    fn is_edge_n(&self) -> bool;
+}
+
+impl CubeEdges for Point2D {
     fn is_edge_a(&self) -> bool {
         (50..=99).contains(&self.x) && self.y == 0
     }
@@ -426,13 +400,3 @@ impl Point {
         (100..=149).contains(&self.x) && self.y == 49
     }
 }
-
-impl Add for &Point {
-    type Output = Point;
-    fn add(self, rhs: Self) -> Self::Output {
-        Point {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
-}
@@ -29,11 +29,7 @@ peg::parser! { pub grammar day23_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<HashSet<Elf>, AOCFileOrParseError> {
-    if let Ok(ret) = day23_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day23_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -1,110 +1,21 @@
-use std::cmp::Ordering;
-
-#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
-pub struct Point {
-    pub x: isize,
-    pub y: isize,
-}
-
-impl PartialOrd for Point {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self.x + self.y > other.x + other.y {
-            Some(Ordering::Greater)
-        } else if self.x + self.y < other.x + other.y {
-            Some(Ordering::Less)
-        } else if self.y > other.y {
-            Some(Ordering::Greater)
-        } else if self.y < other.y {
-            Some(Ordering::Less)
-        } else {
-            None
-        }
-    }
-}
-
-impl Ord for Point {
-    fn cmp(&self, other: &Self) -> Ordering {
-        if self.x + self.y > other.x + other.y {
-            Ordering::Greater
-        } else if self.x + self.y < other.x + other.y {
-            Ordering::Less
-        } else if self.y > other.y {
-            Ordering::Greater
-        } else if self.y < other.y {
-            Ordering::Less
-        } else {
-            Ordering::Equal
-        }
-    }
-}
-
-impl Point {
-    pub fn next_moves(&self) -> Vec<Point> {
-        vec![
-            Point {
-                x: self.x + 1,
-                y: self.y,
-            },
-            Point {
-                x: self.x,
-                y: self.y + 1,
-            },
-            Point {
-                x: self.x,
-                y: self.y,
-            },
-            Point {
-                x: self.x - 1,
-                y: self.y,
-            },
-            Point {
-                x: self.x,
-                y: self.y - 1,
-            },
-        ]
-    }
-}
-
-#[derive(Debug, Eq, PartialEq, Copy, Clone)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+use aoc_helpers::direction::Direction4;
+use aoc_helpers::point2d::Point2D;
 
 pub enum Tile {
     Wall,
     Floor,
-    Blizzard(Direction),
+    Blizzard(Direction4),
 }
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub struct Blizzard {
-    direction: Direction,
-    pub location: Point,
+    direction: Direction4,
+    pub location: Point2D,
 }
 
 impl Blizzard {
     fn next(&self, max_x: usize, max_y: usize) -> Blizzard {
-        let mut location = match self.direction {
-            Direction::Up => Point {
-                x: self.location.x,
-                y: self.location.y - 1,
-            },
-            Direction::Left => Point {
-                x: self.location.x - 1,
-                y: self.location.y,
-            },
-            Direction::Down => Point {
-                x: self.location.x,
-                y: self.location.y + 1,
-            },
-            Direction::Right => Point {
-                x: self.location.x + 1,
-                y: self.location.y,
-            },
-        };
+        let mut location = self.location + self.direction.as_point();
         if location.x < 0 {
             location.x = max_x as isize - 1;
         }
@@ -139,7 +50,7 @@ impl AllBlizzards {
                 if let Tile::Blizzard(dir) = tiles[y][x] {
                     blizzards.push(Blizzard {
                         direction: dir,
-                        location: Point {
+                        location: Point2D {
                             x: x as isize - 1,
                             y: y as isize - 1,
                         },
@@ -166,7 +77,7 @@ impl AllBlizzards {
             // for y in 0..self.height {
             //     for x in 0..self.width {
             //         if next_blizzards.iter().map(|b| b.location).any(|p| {
-            //             p == Point {
+            //             p == Point2D {
             //                 x: x as isize,
             //                 y: y as isize,
             //             }
@@ -183,10 +94,10 @@ impl AllBlizzards {
         self.blizzards_at_time.last().unwrap()
     }
 
-    pub fn move_in_blizzard(&self, next_move: &Point) -> bool {
-        next_move == &Point { x: 0, y: -1 }
+    pub fn move_in_blizzard(&self, next_move: &Point2D) -> bool {
+        next_move == &Point2D { x: 0, y: -1 }
             || next_move
-                == &Point {
+                == &Point2D {
                     x: self.width as isize - 1,
                     y: self.height as isize,
                 }
@@ -194,7 +105,7 @@ impl AllBlizzards {
                 && (0..self.height as isize).contains(&next_move.y))
     }
 
-    pub fn is_move_valid(&mut self, next_move: &Point, at_time: usize) -> bool {
+    pub fn is_move_valid(&mut self, next_move: &Point2D, at_time: usize) -> bool {
         !self
             .get_blizzards_at_time(at_time)
             .iter()
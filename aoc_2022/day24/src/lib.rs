@@ -4,10 +4,11 @@ use std::collections::HashSet;
 use std::collections::VecDeque;
 
 use crate::parser::parse_data;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::direction::Direction4;
+use aoc_helpers::point2d::Point2D;
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 
 use crate::blizzard::AllBlizzards;
-use crate::blizzard::Point;
 
 #[derive(Clone)]
 pub struct Day24 {
@@ -21,18 +22,20 @@ impl AOCCalculator for Day24 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        let mut obj = self.clone();
-        println!("{}a answer is {:?}", name, obj.calculate_day_a());
-        println!("{}b answer is {:?}", name, obj.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.clone().calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.clone().calculate_day_b().into()
     }
 }
 
 impl Day24 {
     fn calculate_day_a(&mut self) -> usize {
         self.find_time_until(
-            &Point { x: 0, y: -1 },
-            &Point {
+            &Point2D { x: 0, y: -1 },
+            &Point2D {
                 x: self.blizzards.width as isize - 1,
                 y: self.blizzards.height as isize,
             },
@@ -41,8 +44,8 @@ impl Day24 {
     }
 
     fn calculate_day_b(&mut self) -> usize {
-        let start = Point { x: 0, y: -1 };
-        let end = Point {
+        let start = Point2D { x: 0, y: -1 };
+        let end = Point2D {
             x: self.blizzards.width as isize - 1,
             y: self.blizzards.height as isize,
         };
@@ -55,8 +58,8 @@ impl Day24 {
 impl Day24 {
     fn find_time_until(
         &mut self,
-        current_node: &Point,
-        target: &Point,
+        current_node: &Point2D,
+        target: &Point2D,
         starting_time: usize,
     ) -> usize {
         let mut stack = VecDeque::new();
@@ -78,9 +81,11 @@ impl Day24 {
             if best_time.is_some() && time_taken >= best_time.unwrap() {
                 continue;
             }
-            let next_moves = current_node
-                .next_moves()
-                .into_iter()
+            // Waiting where we are is a move too
+            let next_moves = Direction4::ALL
+                .iter()
+                .map(|direction| current_node + direction.as_point())
+                .chain([current_node])
                 .filter(|next_move| self.blizzards.is_move_valid(next_move, time_taken + 1));
             for next_move in next_moves {
                 stack.push_back((next_move, time_taken + 1));
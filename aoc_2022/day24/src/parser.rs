@@ -1,5 +1,6 @@
 extern crate peg;
-use crate::blizzard::{AllBlizzards, Direction, Tile};
+use crate::blizzard::{AllBlizzards, Tile};
+use aoc_helpers::direction::Direction4;
 use aoc_helpers::AOCFileOrParseError;
 
 peg::parser! { pub grammar day24_parser() for str {
@@ -9,14 +10,14 @@ peg::parser! { pub grammar day24_parser() for str {
         = "." { Tile::Floor }
     rule wall() -> Tile
         = "#" { Tile::Wall }
-    rule up() -> Direction
-        = "^" { Direction::Up }
-    rule down() -> Direction
-        = "v" { Direction::Down }
-    rule left() -> Direction
-        = "<" { Direction::Left }
-    rule right() -> Direction
-        = ">" { Direction::Right }
+    rule up() -> Direction4
+        = "^" { Direction4::Up }
+    rule down() -> Direction4
+        = "v" { Direction4::Down }
+    rule left() -> Direction4
+        = "<" { Direction4::Left }
+    rule right() -> Direction4
+        = ">" { Direction4::Right }
     rule blizzard() -> Tile
         = direction:(up() / left() / down() / right()) { Tile::Blizzard(direction) }
     rule tile() -> Tile
@@ -30,11 +31,7 @@ peg::parser! { pub grammar day24_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<AllBlizzards, AOCFileOrParseError> {
-    if let Ok(ret) = day24_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day24_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
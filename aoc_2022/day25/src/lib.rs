@@ -2,7 +2,7 @@ mod parser;
 mod snafu;
 use crate::parser::parse_data;
 use crate::snafu::Snafu;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 
 pub struct Day25 {
     snafu_numbers: Vec<Vec<isize>>,
@@ -15,8 +15,12 @@ impl AOCCalculator for Day25 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {}", name, self.calculate_day_a().to_string());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().to_string().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        Answer::None
     }
 }
 
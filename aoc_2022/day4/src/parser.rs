@@ -16,11 +16,7 @@ peg::parser! { pub grammar day4_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<Assignment>, AOCFileOrParseError> {
-    if let Ok(ret) = day4_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day4_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
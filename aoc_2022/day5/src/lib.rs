@@ -1,7 +1,7 @@
 mod parser;
 mod stack_set;
 use crate::parser::parse_data;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 use stack_set::{Instruction, StackSet};
 
 pub struct Day5 {
@@ -18,9 +18,12 @@ impl AOCCalculator for Day5 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {}", name, self.calculate_day_a());
-        println!("{}b answer is {}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -50,11 +50,7 @@ fn convert_horizontal_to_vertical(
 pub fn parse_data(
     input: &str,
 ) -> Result<(HashVec<usize, char>, Vec<Instruction>), AOCFileOrParseError> {
-    if let Ok(ret) = day5_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day5_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -32,11 +32,9 @@ peg::parser! { pub grammar day7_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<usize>, AOCFileOrParseError> {
-    if let Ok(terminal_lines) = day7_parser::parse(input) {
-        Ok(build_sizes(&terminal_lines))
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day7_parser::parse(input)
+        .map(|terminal_lines| build_sizes(&terminal_lines))
+        .map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 fn build_sizes(lines: &[TerminalLine]) -> Vec<usize> {
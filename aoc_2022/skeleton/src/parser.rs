@@ -11,11 +11,9 @@ peg::parser! { pub grammar skeleton_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<(), AOCFileOrParseError> {
-    if let Ok(_ret) = skeleton_parser::parse(input) {
-        Ok(())
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    skeleton_parser::parse(input)
+        .map(|_ret| ())
+        .map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
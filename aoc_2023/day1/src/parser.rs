@@ -51,19 +51,11 @@ peg::parser! { pub grammar day1_parser() for str {
 }}
 
 pub fn parse_data_a(input: &str) -> Result<Vec<Vec<usize>>, AOCFileOrParseError> {
-    if let Ok(ret) = day1_parser::parse_a(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day1_parser::parse_a(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 pub fn parse_data_b(input: &str) -> Result<Vec<Vec<usize>>, AOCFileOrParseError> {
-    if let Ok(ret) = day1_parser::parse_b(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day1_parser::parse_b(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 #[cfg(test)]
 mod test {
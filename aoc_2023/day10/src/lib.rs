@@ -5,7 +5,7 @@ use crate::parser::parse_data;
 use crate::pipe::Pipe;
 use crate::point::Neighbours;
 use aoc_helpers::point2d::Point2D;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet, VecDeque};
 
@@ -20,9 +20,12 @@ impl AOCCalculator for Day10 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -2,7 +2,7 @@ mod parser;
 mod point;
 use crate::parser::parse_data;
 use aoc_helpers::point2d::Point2D;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 use itertools::Itertools;
 
 pub struct Day11 {
@@ -31,9 +31,12 @@ impl AOCCalculator for Day11 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
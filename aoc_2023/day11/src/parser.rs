@@ -15,11 +15,7 @@ peg::parser! { pub grammar day11_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<Vec<bool>>, AOCFileOrParseError> {
-    if let Ok(ret) = day11_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day11_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use aoc_helpers::memo::Memo;
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct ConditionReport {
@@ -6,12 +6,6 @@ pub struct ConditionReport {
     criteria: Vec<usize>,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
-struct CriterionState {
-    current_damaged_len: usize,
-    current_index: usize,
-}
-
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum HotSpringCondition {
     Damaged,
@@ -19,15 +13,6 @@ pub enum HotSpringCondition {
     Unknown,
 }
 
-impl CriterionState {
-    fn first() -> CriterionState {
-        CriterionState {
-            current_damaged_len: 0,
-            current_index: 0,
-        }
-    }
-}
-
 impl ConditionReport {
     pub fn new(hot_spring: Vec<HotSpringCondition>, criteria: Vec<usize>) -> ConditionReport {
         ConditionReport {
@@ -36,77 +21,43 @@ impl ConditionReport {
         }
     }
 
-    fn get_permutations(&self) -> HashMap<CriterionState, usize> {
-        let mut ret = HashMap::new();
-        ret.insert(CriterionState::first(), 1);
-        for value in self.hot_spring.iter() {
-            ret = match value {
-                HotSpringCondition::Damaged => self.get_next_damaged(&ret),
-                HotSpringCondition::Undamaged => self.get_next_undamaged(&ret),
-                HotSpringCondition::Unknown => self.get_next_unknown(&ret),
-            };
-        }
-        ret
-    }
-
-    fn get_next_undamaged(
-        &self,
-        previous: &HashMap<CriterionState, usize>,
-    ) -> HashMap<CriterionState, usize> {
-        let mut ret = HashMap::new();
-        for (key, value) in previous.iter() {
-            if key.current_damaged_len == 0 {
-                *ret.entry(*key).or_insert(0) += value;
-            } else if key.current_damaged_len == self.criteria[key.current_index] {
-                *ret.entry(CriterionState {
-                    current_damaged_len: 0,
-                    current_index: key.current_index + 1,
-                })
-                .or_insert(0) += value;
-            }
-        }
-        ret
-    }
-
-    fn get_next_damaged(
+    /// The number of ways the damaged runs from `criterion` onwards can be laid over the
+    /// springs from `spring` onwards
+    fn count_arrangements(
         &self,
-        previous: &HashMap<CriterionState, usize>,
-    ) -> HashMap<CriterionState, usize> {
-        let mut ret = HashMap::new();
-        for (key, value) in previous.iter() {
-            if key.current_index < self.criteria.len()
-                && key.current_damaged_len < self.criteria[key.current_index]
-            {
-                *ret.entry(CriterionState {
-                    current_damaged_len: key.current_damaged_len + 1,
-                    current_index: key.current_index,
-                })
-                .or_insert(0) += value
-            }
+        memo: &mut Memo<(usize, usize), usize>,
+        spring: usize,
+        criterion: usize,
+    ) -> usize {
+        let remaining = &self.hot_spring[spring..];
+        let Some(&run) = self.criteria.get(criterion) else {
+            return if remaining.contains(&HotSpringCondition::Damaged) {
+                0
+            } else {
+                1
+            };
+        };
+        let mut count = 0;
+        if remaining
+            .first()
+            .is_some_and(|&condition| condition != HotSpringCondition::Damaged)
+        {
+            count += memo.get((spring + 1, criterion));
         }
-        ret
-    }
-
-    fn get_next_unknown(
-        &self,
-        previous: &HashMap<CriterionState, usize>,
-    ) -> HashMap<CriterionState, usize> {
-        let mut ret = self.get_next_damaged(previous);
-        for (key, value) in self.get_next_undamaged(previous) {
-            *ret.entry(key).or_insert(0) += value;
+        // The run can start here if it fits, and isn't followed straight away by more damage
+        if run <= remaining.len()
+            && !remaining[..run].contains(&HotSpringCondition::Undamaged)
+            && remaining.get(run) != Some(&HotSpringCondition::Damaged)
+        {
+            let after_gap = (spring + run + 1).min(self.hot_spring.len());
+            count += memo.get((after_gap, criterion + 1));
         }
-        ret
+        count
     }
 
     pub fn solve_day_a(&self) -> usize {
-        let permutations = self.get_permutations();
-        let final_permute = self.get_next_undamaged(&permutations);
-        *final_permute
-            .get(&CriterionState {
-                current_damaged_len: 0,
-                current_index: self.criteria.len(),
-            })
-            .unwrap_or(&0)
+        Memo::new(|memo, &(spring, criterion)| self.count_arrangements(memo, spring, criterion))
+            .get((0, 0))
     }
 
     pub fn solve_day_b(&self) -> usize {
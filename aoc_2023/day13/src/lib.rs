@@ -3,7 +3,7 @@ use std::cmp::min;
 
 use crate::parser::parse_data;
 use aoc_helpers::vec::Transposable;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 use parser::Tile;
 
 pub struct Day13 {
@@ -17,9 +17,12 @@ impl AOCCalculator for Day13 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
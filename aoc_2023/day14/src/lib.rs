@@ -1,8 +1,9 @@
 mod parser;
 use crate::parser::parse_data;
 use crate::parser::Tile;
+use aoc_helpers::cycle::state_after;
 use aoc_helpers::vec::Rotatable;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 
 #[derive(Clone)]
 pub struct Day14 {
@@ -16,9 +17,12 @@ impl AOCCalculator for Day14 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -91,12 +95,12 @@ impl Day14 {
     }
 
     fn calculate_day_b(&self) -> usize {
-        let mut obj = self.to_owned();
-        for _ in 0..1000 {
-            // just tried 1000 instead of 1billion and it worked.. lol
-            // TODO: actually do cycle detection for this
-            obj.run_one_cycle();
-        }
+        let obj = state_after(
+            self,
+            |obj| obj.run_one_cycle(),
+            |obj| obj.tiles.clone(),
+            1000000000,
+        );
         obj.calculate_load()
     }
 }
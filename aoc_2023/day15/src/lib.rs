@@ -4,7 +4,7 @@ mod step;
 use crate::lens_box::{LensBoxes, LensBoxesType};
 use crate::parser::parse_data;
 use crate::step::{AOCHash, Step};
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Day15 {
@@ -18,9 +18,12 @@ impl AOCCalculator for Day15 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -1,92 +1,76 @@
+use aoc_helpers::direction::Direction4;
 use aoc_helpers::point2d::Point2D;
 
 use crate::room::Room;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum Direction {
-    Up,
-    Right,
-    Left,
-    Down,
-}
-
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Laser {
     pub location: Point2D,
-    pub direction: Direction,
+    pub direction: Direction4,
 }
 
 impl Laser {
-    pub fn new(location: Point2D, direction: Direction) -> Laser {
+    pub fn new(location: Point2D, direction: Direction4) -> Laser {
         Laser {
             location,
             direction,
         }
     }
     pub fn next_movement(&self) -> Laser {
-        Laser::new(
-            self.location
-                + match self.direction {
-                    Direction::Up => Point2D { x: 0, y: -1 },
-                    Direction::Right => Point2D { x: 1, y: 0 },
-                    Direction::Left => Point2D { x: -1, y: 0 },
-                    Direction::Down => Point2D { x: 0, y: 1 },
-                },
-            self.direction,
-        )
+        Laser::new(self.location + self.direction.as_point(), self.direction)
     }
 
     pub fn next_direction(&self, room: &Option<Room>) -> Vec<Laser> {
         match (self.direction, room) {
             (_, None) => vec![],
             (_, Some(Room::Empty)) => vec![*self],
-            (Direction::Up, Some(Room::SplitterVertical)) => vec![*self],
-            (Direction::Down, Some(Room::SplitterVertical)) => vec![*self],
-            (Direction::Left, Some(Room::SplitterHorizontal)) => vec![*self],
-            (Direction::Right, Some(Room::SplitterHorizontal)) => vec![*self],
-            (Direction::Up, Some(Room::SplitterHorizontal)) => vec![
-                Laser::new(self.location, Direction::Left),
-                Laser::new(self.location, Direction::Right),
+            (Direction4::Up, Some(Room::SplitterVertical)) => vec![*self],
+            (Direction4::Down, Some(Room::SplitterVertical)) => vec![*self],
+            (Direction4::Left, Some(Room::SplitterHorizontal)) => vec![*self],
+            (Direction4::Right, Some(Room::SplitterHorizontal)) => vec![*self],
+            (Direction4::Up, Some(Room::SplitterHorizontal)) => vec![
+                Laser::new(self.location, Direction4::Left),
+                Laser::new(self.location, Direction4::Right),
             ],
-            (Direction::Down, Some(Room::SplitterHorizontal)) => vec![
-                Laser::new(self.location, Direction::Left),
-                Laser::new(self.location, Direction::Right),
+            (Direction4::Down, Some(Room::SplitterHorizontal)) => vec![
+                Laser::new(self.location, Direction4::Left),
+                Laser::new(self.location, Direction4::Right),
             ],
-            (Direction::Left, Some(Room::SplitterVertical)) => {
+            (Direction4::Left, Some(Room::SplitterVertical)) => {
                 vec![
-                    Laser::new(self.location, Direction::Up),
-                    Laser::new(self.location, Direction::Down),
+                    Laser::new(self.location, Direction4::Up),
+                    Laser::new(self.location, Direction4::Down),
                 ]
             }
-            (Direction::Right, Some(Room::SplitterVertical)) => {
+            (Direction4::Right, Some(Room::SplitterVertical)) => {
                 vec![
-                    Laser::new(self.location, Direction::Up),
-                    Laser::new(self.location, Direction::Down),
+                    Laser::new(self.location, Direction4::Up),
+                    Laser::new(self.location, Direction4::Down),
                 ]
             }
-            (Direction::Up, Some(Room::DiagonalForward)) => {
-                vec![Laser::new(self.location, Direction::Right)]
+            (Direction4::Up, Some(Room::DiagonalForward)) => {
+                vec![Laser::new(self.location, Direction4::Right)]
             }
-            (Direction::Up, Some(Room::DiagonalBackward)) => {
-                vec![Laser::new(self.location, Direction::Left)]
+            (Direction4::Up, Some(Room::DiagonalBackward)) => {
+                vec![Laser::new(self.location, Direction4::Left)]
             }
-            (Direction::Down, Some(Room::DiagonalForward)) => {
-                vec![Laser::new(self.location, Direction::Left)]
+            (Direction4::Down, Some(Room::DiagonalForward)) => {
+                vec![Laser::new(self.location, Direction4::Left)]
             }
-            (Direction::Down, Some(Room::DiagonalBackward)) => {
-                vec![Laser::new(self.location, Direction::Right)]
+            (Direction4::Down, Some(Room::DiagonalBackward)) => {
+                vec![Laser::new(self.location, Direction4::Right)]
             }
-            (Direction::Right, Some(Room::DiagonalForward)) => {
-                vec![Laser::new(self.location, Direction::Up)]
+            (Direction4::Right, Some(Room::DiagonalForward)) => {
+                vec![Laser::new(self.location, Direction4::Up)]
             }
-            (Direction::Right, Some(Room::DiagonalBackward)) => {
-                vec![Laser::new(self.location, Direction::Down)]
+            (Direction4::Right, Some(Room::DiagonalBackward)) => {
+                vec![Laser::new(self.location, Direction4::Down)]
             }
-            (Direction::Left, Some(Room::DiagonalForward)) => {
-                vec![Laser::new(self.location, Direction::Down)]
+            (Direction4::Left, Some(Room::DiagonalForward)) => {
+                vec![Laser::new(self.location, Direction4::Down)]
             }
-            (Direction::Left, Some(Room::DiagonalBackward)) => {
-                vec![Laser::new(self.location, Direction::Up)]
+            (Direction4::Left, Some(Room::DiagonalBackward)) => {
+                vec![Laser::new(self.location, Direction4::Up)]
             }
         }
     }
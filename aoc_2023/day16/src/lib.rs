@@ -1,12 +1,13 @@
 mod laser;
 mod parser;
 mod room;
-use crate::laser::{Direction, Laser};
+use crate::laser::Laser;
 use std::collections::HashSet;
 
 use crate::parser::parse_data;
 use crate::room::Room;
-use aoc_helpers::{point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::direction::Direction4;
+use aoc_helpers::{point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Day16 {
@@ -20,9 +21,12 @@ impl AOCCalculator for Day16 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -57,7 +61,7 @@ impl Day16 {
     }
 
     fn calculate_day_a(&self) -> usize {
-        self.get_energy_from_starting_laser(&Laser::new(Point2D { x: -1, y: 0 }, Direction::Right))
+        self.get_energy_from_starting_laser(&Laser::new(Point2D { x: -1, y: 0 }, Direction4::Right))
     }
 
     #[allow(dead_code)]
@@ -87,14 +91,14 @@ impl Day16 {
                     x: -1,
                     y: y as isize,
                 },
-                Direction::Right,
+                Direction4::Right,
             ));
             ret.push(Laser::new(
                 Point2D {
                     x: self.layout[y].len() as isize,
                     y: y as isize,
                 },
-                Direction::Left,
+                Direction4::Left,
             ));
         }
         for x in 0..self.layout[0].len() {
@@ -103,14 +107,14 @@ impl Day16 {
                     x: x as isize,
                     y: -1,
                 },
-                Direction::Down,
+                Direction4::Down,
             ));
             ret.push(Laser::new(
                 Point2D {
                     x: x as isize,
                     y: self.layout[0].len() as isize,
                 },
-                Direction::Down,
+                Direction4::Down,
             ));
         }
         ret
@@ -1,33 +1,16 @@
+use aoc_helpers::direction::Direction4;
 use aoc_helpers::point2d::Point2D;
-#[derive(Debug, Clone, PartialEq, Eq, Copy, Hash)]
-enum Direction {
-    Up,
-    Left,
-    Right,
-    Down,
-}
 
 #[derive(Debug, Clone, PartialEq, Eq, Copy, Hash)]
 pub struct Crucible {
-    dir: Direction,
+    dir: Direction4,
     pub length: usize,
     pub min: usize,
     max: usize,
 }
 
-impl Direction {
-    fn as_point_delta(&self) -> Point2D {
-        match self {
-            Direction::Up => Point2D { x: 0, y: -1 },
-            Direction::Left => Point2D { x: -1, y: 0 },
-            Direction::Right => Point2D { x: 1, y: 0 },
-            Direction::Down => Point2D { x: 0, y: 1 },
-        }
-    }
-}
-
 impl Crucible {
-    fn init(&self, dir: Direction) -> Crucible {
+    fn init(&self, dir: Direction4) -> Crucible {
         Crucible {
             dir,
             length: 1,
@@ -51,12 +34,10 @@ impl Crucible {
             ret.push(self.one_larger())
         }
         if self.length >= self.min {
-            ret.extend(match self.dir {
-                Direction::Up => vec![self.init(Direction::Left), self.init(Direction::Right)],
-                Direction::Left => vec![self.init(Direction::Up), self.init(Direction::Down)],
-                Direction::Right => vec![self.init(Direction::Up), self.init(Direction::Down)],
-                Direction::Down => vec![self.init(Direction::Left), self.init(Direction::Right)],
-            });
+            ret.extend([
+                self.init(self.dir.turn_left()),
+                self.init(self.dir.turn_right()),
+            ]);
         }
         ret
     }
@@ -72,7 +53,7 @@ impl CrucibleLocation {
     pub fn get_starting_crucible_location(min: usize, max: usize) -> CrucibleLocation {
         CrucibleLocation {
             crucible: Crucible {
-                dir: Direction::Right,
+                dir: Direction4::Right,
                 length: 0,
                 min,
                 max,
@@ -87,7 +68,7 @@ impl CrucibleLocation {
             .into_iter()
             .map(|neighbour_crucible| CrucibleLocation {
                 crucible: neighbour_crucible,
-                location: self.location + neighbour_crucible.dir.as_point_delta(),
+                location: self.location + neighbour_crucible.dir.as_point(),
             })
             .collect()
     }
@@ -1,10 +1,9 @@
 mod crucible;
 mod parser;
-use std::collections::{BinaryHeap, HashMap};
-
 use crate::crucible::CrucibleLocation;
 use crate::parser::parse_data;
-use aoc_helpers::{point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::search::dijkstra;
+use aoc_helpers::{point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Day17 {
@@ -18,30 +17,12 @@ impl AOCCalculator for Day17 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
-    }
-}
-
-// we need a single object for the binaryHeap with its custom ordering.
-// In this case, we just rely on the distance for ordering and ignore
-// everything else.
-//
-// It could be an okay idea to use manhattan distance from 0,0 as the
-// next ordering point
-#[derive(Debug, PartialEq, Eq, Clone)]
-struct PathState(CrucibleLocation, usize);
-
-impl Ord for PathState {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.1.cmp(&other.1).reverse()
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
     }
-}
 
-impl PartialOrd for PathState {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -59,61 +40,29 @@ impl Day17 {
         }
     }
 
-    fn is_distance_valid(
-        &self,
-        seen: &HashMap<CrucibleLocation, usize>,
-        final_best: Option<usize>,
-        crucible_location: &CrucibleLocation,
-        distance: usize,
-    ) -> bool {
-        if seen
-            .get(crucible_location)
-            .map(|prev_distance| prev_distance <= &distance)
-            .unwrap_or(false)
-        {
-            return false;
-        }
-        if final_best
-            .map(|final_best| final_best <= distance)
-            .unwrap_or(false)
-        {
-            return false;
-        }
-        true
-    }
-
     fn find_shortest_path(&self, start: CrucibleLocation) -> usize {
-        // essentially dijkstra's algorithm. The main difference
-        // is that instead of assigning each point a "best" value and aborting
-        // early if you exceed that point, we assign the combined
-        // "point + direction + length along that direction" (conveniently labelled as a
-        // crucible_location here).
-        //
-        // Works with a VecDeque, but is slow. Changing to a BinaryHeap sped up from 20s for the
-        // whole process to 0.5 seconds.
-        let mut seen: HashMap<CrucibleLocation, usize> = HashMap::new();
-        let mut paths = BinaryHeap::new();
-        paths.push(PathState(start, 0));
-        let mut final_best = None;
+        // Dijkstra, except the state isn't just the point but the combined "point + direction +
+        // length along that direction" (conveniently labelled as a crucible_location here).
         let end = Point2D {
             x: self.lava_pool[0].len() as isize - 1,
             y: self.lava_pool.len() as isize - 1,
         };
-        while let Some(PathState(crucible_location, distance)) = paths.pop() {
-            if !self.is_distance_valid(&seen, final_best, &crucible_location, distance) {
-                continue;
-            }
-            seen.insert(crucible_location.clone(), distance);
-            if crucible_location.is_at_end(&end) {
-                final_best = Some(distance);
-            }
-            for neighbour in crucible_location.get_neighbours().into_iter() {
-                if let Some(distance_increase) = self.value_at_point(&neighbour.location) {
-                    paths.push(PathState(neighbour, distance + distance_increase));
-                }
-            }
-        }
-        final_best.unwrap_or(0)
+        dijkstra(
+            start,
+            |crucible_location| {
+                crucible_location
+                    .get_neighbours()
+                    .into_iter()
+                    .filter_map(|neighbour| {
+                        let heat_loss = self.value_at_point(&neighbour.location)?;
+                        Some((neighbour, heat_loss))
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |crucible_location| crucible_location.is_at_end(&end),
+        )
+        .expect("The crucible should be able to reach the end")
+        .cost
     }
 
     fn calculate_day_a(&self) -> usize {
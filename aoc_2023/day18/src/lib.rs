@@ -4,7 +4,7 @@ use itertools::Itertools;
 
 use crate::direction::Direction;
 use crate::parser::parse_data;
-use aoc_helpers::{point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Day18 {
@@ -18,9 +18,12 @@ impl AOCCalculator for Day18 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
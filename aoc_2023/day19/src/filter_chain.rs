@@ -44,35 +44,11 @@ mod test {
                     FilterRule::Target("rfg".to_string())
                 ]
             }
-            .next_target_range(&PartRange {
-                x: 1..4001,
-                m: 1..4001,
-                a: 1..4001,
-                s: 1..1351,
-                target: "px".to_string()
-            }),
+            .next_target_range(&PartRange::new([1..4001, 1..4001, 1..4001, 1..1351], "px")),
             vec![
-                PartRange {
-                    x: 1..4001,
-                    m: 1..4001,
-                    a: 1..2006,
-                    s: 1..1351,
-                    target: "qkq".to_string()
-                },
-                PartRange {
-                    x: 1..4001,
-                    m: 1..2091,
-                    a: 2006..4001,
-                    s: 1..1351,
-                    target: "rfg".to_string()
-                },
-                PartRange {
-                    x: 1..4001,
-                    m: 2091..4001,
-                    a: 2006..4001,
-                    s: 1..1351,
-                    target: "A".to_string()
-                }
+                PartRange::new([1..4001, 1..4001, 1..2006, 1..1351], "qkq"),
+                PartRange::new([1..4001, 1..2091, 2006..4001, 1..1351], "rfg"),
+                PartRange::new([1..4001, 2091..4001, 2006..4001, 1..1351], "A")
             ]
         );
     }
@@ -15,6 +15,18 @@ pub enum PartCategory {
     S,
 }
 
+impl PartCategory {
+    /// Which axis of a `PartRange`'s box holds this category
+    pub fn axis(&self) -> usize {
+        match self {
+            PartCategory::X => 0,
+            PartCategory::M => 1,
+            PartCategory::A => 2,
+            PartCategory::S => 3,
+        }
+    }
+}
+
 impl FilterRule {
     pub fn next_target(&self, part: &Part) -> Option<String> {
         match self {
@@ -6,7 +6,7 @@ use std::collections::HashMap;
 
 use crate::parser::parse_data;
 use crate::part::Part;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 use filter_chain::FilterChain;
 use part::PartRange;
 
@@ -25,9 +25,12 @@ impl AOCCalculator for Day19 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
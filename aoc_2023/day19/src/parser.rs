@@ -53,11 +53,7 @@ peg::parser! { pub grammar day19_parser() for str {
 pub fn parse_data(
     input: &str,
 ) -> Result<(HashMap<String, FilterChain>, Vec<Part>), AOCFileOrParseError> {
-    if let Ok(ret) = day19_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day19_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
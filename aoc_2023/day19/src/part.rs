@@ -1,6 +1,7 @@
 use std::ops::Range;
 
 use crate::filter_rule::PartCategory;
+use aoc_helpers::aabox::AABox;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Part {
@@ -25,65 +26,42 @@ impl Part {
     }
 }
 
+/// Every combination of ratings in a box, one axis per category, and the workflow they're at
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct PartRange {
-    pub x: Range<usize>,
-    pub m: Range<usize>,
-    pub a: Range<usize>,
-    pub s: Range<usize>,
+    pub ratings: AABox<4>,
     pub target: String,
 }
 
 impl PartRange {
-    pub fn first() -> PartRange {
+    pub fn new(ratings: [Range<isize>; 4], target: &str) -> PartRange {
         PartRange {
-            x: 1..4001,
-            m: 1..4001,
-            a: 1..4001,
-            s: 1..4001,
-            target: "in".to_string(),
+            ratings: AABox::from_ranges(ratings),
+            target: target.to_string(),
         }
     }
-    pub fn get(&self, part_category: PartCategory) -> Range<usize> {
-        match part_category {
-            PartCategory::X => self.x.clone(),
-            PartCategory::M => self.m.clone(),
-            PartCategory::A => self.a.clone(),
-            PartCategory::S => self.s.clone(),
-        }
+
+    pub fn first() -> PartRange {
+        PartRange::new([1..4001, 1..4001, 1..4001, 1..4001], "in")
     }
 
     pub fn get_size(&self) -> usize {
-        self.x.len() * self.m.len() * self.a.len() * self.s.len()
+        self.ratings.volume()
     }
 
+    #[cfg(test)]
     pub fn clone_with_part(
         &self,
         part_category: PartCategory,
         range: Range<usize>,
         target: &str,
     ) -> PartRange {
-        match part_category {
-            PartCategory::X => PartRange {
-                x: range,
-                target: target.to_string(),
-                ..self.clone()
-            },
-            PartCategory::M => PartRange {
-                m: range,
-                target: target.to_string(),
-                ..self.clone()
-            },
-            PartCategory::A => PartRange {
-                a: range,
-                target: target.to_string(),
-                ..self.clone()
-            },
-            PartCategory::S => PartRange {
-                s: range,
-                target: target.to_string(),
-                ..self.clone()
-            },
+        let mut ratings = self.ratings;
+        ratings.min[part_category.axis()] = range.start as isize;
+        ratings.max[part_category.axis()] = range.end as isize;
+        PartRange {
+            ratings,
+            target: target.to_string(),
         }
     }
 
@@ -101,16 +79,15 @@ impl PartRange {
         lower_target: &str,
         upper_target: &str,
     ) -> Vec<PartRange> {
-        let range = self.get(part_category);
-        if range.contains(&value) {
-            vec![
-                self.clone_with_part(part_category, range.start..value, lower_target),
-                self.clone_with_part(part_category, value..range.end, upper_target),
-            ]
-        } else if value < range.start {
-            vec![self.clone_with_part(part_category, range, upper_target)]
-        } else {
-            vec![self.clone_with_part(part_category, range, lower_target)]
-        }
+        let (lower, upper) = self.ratings.split(part_category.axis(), value as isize);
+        [(lower, lower_target), (upper, upper_target)]
+            .into_iter()
+            .filter_map(|(ratings, target)| {
+                Some(PartRange {
+                    ratings: ratings?,
+                    target: target.to_string(),
+                })
+            })
+            .collect()
     }
 }
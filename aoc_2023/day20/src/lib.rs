@@ -5,8 +5,8 @@ use std::collections::{HashMap, VecDeque};
 use crate::parser::parse_data;
 use crate::pulse_module::PulseModule;
 use aoc_helpers::{
-    hash_utils::HashVec, modular_math::least_common_multiple, read_input_file, AOCCalculator,
-    AOCFileOrParseError,
+    hash_utils::HashVec, modular_math::least_common_multiple_of, read_input_file, AOCCalculator,
+    AOCFileOrParseError, Answer,
 };
 use pulse_module::{Pulse, PulseModuleType};
 
@@ -60,11 +60,12 @@ impl AOCCalculator for Day20 {
         Ok(Day20 { modules, rx_input })
     }
 
-    fn print_results(&self, name: &str) {
-        let mut part_a = self.to_owned();
-        println!("{}a answer is {:?}", name, part_a.calculate_day_a());
-        let mut part_b = self.to_owned();
-        println!("{}b answer is {:?}", name, part_b.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.to_owned().calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.to_owned().calculate_day_b().into()
     }
 }
 
@@ -118,6 +119,10 @@ impl Day20 {
 
     fn calculate_day_b(&mut self) -> usize {
         let rx_input_key_len = self.get_rx_inputs().len();
+        assert!(
+            rx_input_key_len > 0,
+            "The module before rx should be a conjunction with inputs"
+        );
         let mut count_until: HashMap<String, usize> = HashMap::new();
         let mut count = 0;
         loop {
@@ -132,10 +137,8 @@ impl Day20 {
                 break;
             }
         }
-        count_until
-            .into_values()
-            .reduce(least_common_multiple)
-            .unwrap_or(0)
+        least_common_multiple_of(count_until.into_values().map(|count| count as u128))
+            .expect("The rx inputs should all line up before overflowing") as usize
     }
 }
 
@@ -25,11 +25,7 @@ peg::parser! { pub grammar day21_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<Vec<GardenTile>>, AOCFileOrParseError> {
-    if let Ok(ret) = day21_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day21_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
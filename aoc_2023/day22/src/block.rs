@@ -1,6 +1,4 @@
-use std::ops::Range;
-
-use aoc_helpers::point3d::Point3D;
+use aoc_helpers::{aabox::AABox, point3d::Point3D};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Block {
@@ -13,19 +11,9 @@ pub trait Overlaps {
     fn overlaps(&self, other: &Self) -> bool;
 }
 
-impl Overlaps for Range<isize> {
-    fn overlaps(&self, other: &Self) -> bool {
-        !(self.start > other.end || other.start > self.end)
-            && self.end > other.start
-            && other.end > self.start
-    }
-}
-
 impl Overlaps for Block {
     fn overlaps(&self, other: &Self) -> bool {
-        (self.start.x..(self.end.x + 1)).overlaps(&(other.start.x..(other.end.x + 1)))
-            && (self.start.y..(self.end.y + 1)).overlaps(&(other.start.y..(other.end.y + 1)))
-            && (self.start.z..(self.end.z + 1)).overlaps(&(other.start.z..(other.end.z + 1)))
+        self.as_box().overlaps(&other.as_box())
     }
 }
 
@@ -48,6 +36,13 @@ impl Block {
     pub fn is_at_bottom(&self) -> bool {
         self.start.z == 1 || self.end.z == 1
     }
+
+    fn as_box(&self) -> AABox<3> {
+        AABox::from_inclusive(
+            [self.start.x, self.start.y, self.start.z],
+            [self.end.x, self.end.y, self.end.z],
+        )
+    }
 }
 
 #[cfg(test)]
@@ -55,12 +50,25 @@ mod test {
     use super::*;
 
     #[test]
-    fn test_range_overlaps() {
-        assert!((0..8).overlaps(&(2..4)));
-        assert!((2..4).overlaps(&(0..8)));
-        assert!((0..6).overlaps(&(4..8)));
-        assert!((4..8).overlaps(&(0..6)));
-        assert!(!(0..2).overlaps(&(2..4)));
-        assert!(!(2..4).overlaps(&(0..2)));
+    fn test_block_overlaps() {
+        let block = |start: (isize, isize, isize), end: (isize, isize, isize)| {
+            Block::new(
+                Point3D {
+                    x: start.0,
+                    y: start.1,
+                    z: start.2,
+                },
+                Point3D {
+                    x: end.0,
+                    y: end.1,
+                    z: end.2,
+                },
+            )
+        };
+        let a = block((1, 0, 1), (1, 2, 1));
+        assert!(a.overlaps(&block((0, 0, 1), (2, 0, 1))));
+        assert!(a.overlaps(&block((1, 2, 1), (1, 2, 5))));
+        assert!(!a.overlaps(&block((0, 0, 2), (2, 0, 2))));
+        assert!(!a.overlaps(&block((2, 0, 1), (2, 2, 1))));
     }
 }
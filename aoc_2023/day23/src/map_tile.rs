@@ -1,4 +1,4 @@
-use aoc_helpers::point2d::Point2D;
+use aoc_helpers::direction::Direction4;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum MapTile {
@@ -11,37 +11,23 @@ pub enum MapTile {
 }
 
 impl MapTile {
-    pub fn get_neighbour_deltas(&self) -> Vec<Point2D> {
+    /// The directions this tile can be left in, going down the slopes
+    pub fn get_exits(&self) -> Vec<Direction4> {
         match self {
             MapTile::Forest => vec![],
-            MapTile::Path => vec![
-                Point2D { x: -1, y: 0 },
-                Point2D { x: 1, y: 0 },
-                Point2D { x: 0, y: -1 },
-                Point2D { x: 0, y: 1 },
-            ],
-            MapTile::SlopeTopBottom => vec![Point2D { x: 0, y: 1 }],
-            MapTile::SlopeBottomTop => vec![Point2D { x: 0, y: -1 }],
-            MapTile::SlopeLeftRight => vec![Point2D { x: 1, y: 0 }],
-            MapTile::SlopeRightLeft => vec![Point2D { x: -1, y: 0 }],
+            MapTile::Path => Direction4::ALL.to_vec(),
+            MapTile::SlopeTopBottom => vec![Direction4::Down],
+            MapTile::SlopeBottomTop => vec![Direction4::Up],
+            MapTile::SlopeLeftRight => vec![Direction4::Right],
+            MapTile::SlopeRightLeft => vec![Direction4::Left],
         }
     }
 
-    pub fn is_slope_tile(&self) -> bool {
-        matches!(
-            self,
-            MapTile::SlopeTopBottom
-                | MapTile::SlopeBottomTop
-                | MapTile::SlopeLeftRight
-                | MapTile::SlopeRightLeft
-        )
-    }
-
-    pub fn is_path(&self) -> bool {
-        matches!(self, MapTile::Path)
-    }
-
-    pub fn is_path_or_slope_tile(&self) -> bool {
-        self.is_path() || self.is_slope_tile()
+    /// The directions this tile can be left in, with slopes as easy to climb as paths
+    pub fn get_exits_ignoring_slopes(&self) -> Vec<Direction4> {
+        match self {
+            MapTile::Forest => vec![],
+            _ => Direction4::ALL.to_vec(),
+        }
     }
 }
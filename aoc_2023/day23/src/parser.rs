@@ -26,11 +26,7 @@ peg::parser! { pub grammar day23_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<Vec<MapTile>>, AOCFileOrParseError> {
-    if let Ok(ret) = day23_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day23_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -1,8 +1,9 @@
 mod parser;
 
 use crate::parser::parse_data;
+use aoc_helpers::linear_algebra::{gcd, solve, Rational};
 use aoc_helpers::point3d::Point3D;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 use itertools::Itertools;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -17,9 +18,12 @@ impl AOCCalculator for Day24 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -157,176 +161,83 @@ impl Day24 {
         self.calculate_day_a_for_bounds(200000000000000., 400000000000000.)
     }
 
-    fn build_matrix_for_hailstones(
-        &self,
-        hailstone_1: &(Point3D, Point3D),
-        hailstone_2: &(Point3D, Point3D),
-    ) -> Vec<Vec<f64>> {
-        // https://www.reddit.com/r/adventofcode/comments/18pnycy/2023_day_24_solutions/kepu26z/
-        //
-        // I was mostly on the right track, the key was multiplying the cross-product to remove
-        // the `t` term.
-        //
-        // p0 + t * v0 = pi + t * vi
-        // (p0 - pi) = (vi - v0) * t
-        //
-        // cross product both sides by (vi - v0)
-        // (p0 - pi) x (v0 - vi) = (vi - v0) x (v0 - v1) * t, but (vi - v0) x (v0 - v1) == 0 due to
-        // being parallel (0 being the 3d vector of all 0s)
-        //
-        // (p0 - pi) x (v0 - vi) = 0
-        //
-        // which comes out as p0 x v0 - pi x v0 - p0 x vi + pi x vi = 0 (I have confirmed cross
-        // products are distributive. They are not quite commutative but should work -1 * magnitude
-        // when reversed, which I think is fine for an `== 0` term.)
-        //
-        // Unfortunately there's still the p0 * v0 term, but this is common across all hailstones,
-        // so if we grab another hailstone pj/vj and right the same equation
-        //
-        // p0 x v0 - pj x v0 - p0 x vj + pj x vj = 0
-        //
-        // and set them equal to each other:
-        // p0 x v0 - pj x v0 - p0 x vj + pj x vj = p0 x v0 - pi x v0 - p0 x vi + pi x vi
-        //
-        // then the p0 x v0 terms will cancel out, and we can create 1 equation:
-        // pi x v0 + p0 x vi - pi x vi - pj x v0 - p0 x vj + pj x vj = 0
-        // (pi - pj) x v0 + (vi - vj) x p0 + (pj x vj - pi x vi) x 1 (scalar) = 0
-        //
-        // Of note: `x` refers to the cross product, that is, if c = a x b, then c.x = a.y * b.z -
-        // b.y * a.z
-        //
-        // So translating this to x/y/z formulas dependant on x/y/z we get:
-        //
-        // (pi - pj).y * v0.z - (pi - pj).z * v0.y + (vi - vj).y * p0.z - (vi - vj).z * p0.y +
-        //  pj.y * vj.z - pj.z * vj.y - (pi.y * vi.z - pi.z * vi.y) == 0 (0.x)
-        //
-        // (pi - pj).z * v0.x - (pi - pj).x * v0.z + (vi - vj).z * p0.x - (vi - vj).x * p0.z +
-        //  pj.z * vj.x - pj.x * vj.z - (pi.z * vi.x - pi.x * vi.z) == 0 (0.y)
-        //
-        // (pi - pj).x * v0.y - (pi - pj).y * v0.x + (vi - vj).x * p0.y - (vi - vj).y * p0.x +
-        //  pj.x * vj.y - pj.y * vj.x - (pi.x * vi.y - pi.y * vi.x) == 0 (0.z)
-        //
-        //
-        // These 2 terms will give us 3 vectors, so if we add a 3rd hailstone we can get the 6
-        // vectors required to do a full gaussian elimination
-        let pi = hailstone_1.0;
-        let pj = hailstone_2.0;
-        let vi = hailstone_1.1;
-        let vj = hailstone_2.1;
-        vec![
-            // (pi - pj).y * v0.z - (pi - pj).z * v0.y + (vi - vj).y * p0.z - (vi - vj).z * p0.y +
-            //  pj.y * vj.z - pj.z * vj.y - (pi.y * vi.z - pi.z * vi.y) == 0 (0.x)
-            vec![
-                0.0,
-                -(&vi - &vj).z as f64,
-                (&vi - &vj).y as f64,
-                0.0,
-                -(&pi - &pj).z as f64,
-                (&pi - &pj).y as f64,
-                pj.y as f64 * vj.z as f64
-                    - pj.z as f64 * vj.y as f64
-                    - (pi.y as f64 * vi.z as f64 - pi.z as f64 * vi.y as f64),
-            ],
-            // (pi - pj).z * v0.x - (pi - pj).x * v0.z + (vi - vj).z * p0.x - (vi - vj).x * p0.z +
-            //  pj.z * vj.x - pj.x * vj.z - (pi.z * vi.x - pi.x * vi.z) == 0 (0.y)
-            vec![
-                (&vi - &vj).z as f64,
-                0.0,
-                -(&vi - &vj).x as f64,
-                (&pi - &pj).z as f64,
-                0.0,
-                -(&pi - &pj).x as f64,
-                pj.z as f64 * vj.x as f64
-                    - pj.x as f64 * vj.z as f64
-                    - (pi.z as f64 * vi.x as f64 - pi.x as f64 * vi.z as f64),
-            ],
-            // (pi - pj).x * v0.y - (pi - pj).y * v0.x + (vi - vj).x * p0.y - (vi - vj).y * p0.x +
-            //  pj.x * vj.y - pj.y * vj.x - (pi.x * vi.y - pi.y * vi.x) == 0 (0.z)
-            vec![
-                -(&vi - &vj).y as f64,
-                (&vi - &vj).x as f64,
-                0.0,
-                -(&pi - &pj).y as f64,
-                (&pi - &pj).x as f64,
-                0.0,
-                pj.x as f64 * vj.y as f64
-                    - pj.y as f64 * vj.x as f64
-                    - (pi.x as f64 * vi.y as f64 - pi.y as f64 * vi.x as f64),
-            ],
-        ]
-    }
-
-    fn format_matrix(&self, matrix: &[Vec<f64>]) -> String {
-        let mut ret = String::new();
-        ret += "\n";
-        for line in matrix.iter() {
-            for point in line.iter() {
-                ret += &point.to_string();
-                ret += "          ";
-            }
-            ret += "\n";
-        }
-        ret
-    }
-
-    fn gaussian_elimination(&self, matrix: Vec<Vec<f64>>) -> Vec<f64> {
-        let mut matrix = matrix;
-        let mut normaliser_index = [None; 6];
-        for i in 0..6 {
-            #[allow(clippy::needless_range_loop)]
-            for j in 0..6 {
-                if !normaliser_index[0..i].contains(&Some(j)) && matrix[j][i].abs() > 0.1 {
-                    normaliser_index[i] = Some(j);
-                    break;
-                }
-            }
-            if normaliser_index[i].is_none() {
-                panic!(
-                    "Could not find a normaliser index, {}, {:?}, {}",
-                    i,
-                    normaliser_index,
-                    self.format_matrix(&matrix)
-                );
-            }
-            let index = normaliser_index[i].unwrap();
-            let normaliser = matrix[index][i];
-            for j in i..7 {
-                matrix[index][j] /= normaliser;
-            }
-            for j in 0..6 {
-                if j == index {
-                    continue;
-                }
-                for k in (i..7).rev() {
-                    matrix[j][k] -= matrix[j][i] * matrix[index][k];
-                }
-            }
-        }
-        (0..6)
-            .map(|i| matrix[normaliser_index[i].unwrap()][6])
-            .collect()
+    fn time_of_collision(&self, hailstone: &(Point3D, Point3D), direction: [i128; 3]) -> i128 {
+        // Relative to the first hailstone the rock's path goes through the origin, so it hits
+        // this hailstone when `p + t * v == s * direction` for some `s`. That's 3 equations for
+        // 2 unknowns, `t` and `s`.
+        let position = to_i128(hailstone.0 - self.hailstones[0].0);
+        let velocity = to_i128(hailstone.1 - self.hailstones[0].1);
+        let matrix: Vec<Vec<i128>> = (0..3)
+            .map(|axis| vec![velocity[axis], -direction[axis]])
+            .collect();
+        let constants: Vec<i128> = position.iter().map(|p| -p).collect();
+        solve(&matrix, &constants).expect("The rock should hit every hailstone")[0]
+            .to_integer()
+            .expect("The rock should hit hailstones on whole nanoseconds")
     }
 
     fn calculate_day_b(&self) -> usize {
-        // I think we can mostly define this as a series of simultaneous equations and solve
-        // We need to find x/dx, y/dy, z/dz for the input rock, which we'll say are a/b, c/d, e/f
-        // x + dx t = a + b t
-        // y + dy t = c + d t
-        // z + dz t = e + f t
+        // Look at everything relative to the first hailstone, which then sits still at the
+        // origin.
         //
-        // for a given hailstone, we need to ensure that t can be valid for all, and then reduce
-        // down.
+        // The rock has to go through the origin, and also somewhere along the path of each other
+        // hailstone, so its path lies in the plane containing the origin and that hailstone's
+        // path. The normal of that plane is p x v, and the rock's direction is perpendicular to
+        // the normals of 2 such planes, which is their cross product.
         //
-        // There are essentially 6 unknowns, so I think 6 hailstones should be sufficient to get
-        // an answer, although maybe t counts as an unknown in which case you'll need some
-        // way of dealing with that.
-        let mut matrix = self.build_matrix_for_hailstones(&self.hailstones[0], &self.hailstones[1]);
-        matrix.extend(self.build_matrix_for_hailstones(&self.hailstones[0], &self.hailstones[3]));
-        let rock = self.gaussian_elimination(matrix);
-        rock[0..3].iter().map(|p| p.round().abs() as usize).sum()
+        // Knowing the direction gives the time the rock hits each hailstone, and knowing where
+        // it is at 2 different times gives its starting position.
+        let direction = self.hailstones[1..]
+            .iter()
+            .map(|hailstone| {
+                cross(
+                    to_i128(hailstone.0 - self.hailstones[0].0),
+                    to_i128(hailstone.1 - self.hailstones[0].1),
+                )
+            })
+            .tuple_combinations()
+            .map(|(first, second)| cross(first, second))
+            .find(|direction| direction != &[0, 0, 0])
+            .expect("2 hailstones should give different planes through the first");
+        let divisor = direction.iter().fold(0, |divisor, &d| gcd(divisor, d));
+        let direction = direction.map(|d| d / divisor);
+        let ((time_1, hit_1), (time_2, hit_2)) = self.hailstones[1..]
+            .iter()
+            .map(|hailstone| {
+                let time = self.time_of_collision(hailstone, direction);
+                let position = to_i128(hailstone.0);
+                let velocity = to_i128(hailstone.1);
+                (
+                    time,
+                    [0, 1, 2].map(|axis| position[axis] + time * velocity[axis]),
+                )
+            })
+            .tuple_combinations()
+            .find(|((time_1, _), (time_2, _))| time_1 != time_2)
+            .expect("The rock should hit 2 hailstones at different times");
+        (0..3)
+            .map(|axis| {
+                let velocity = Rational::new(hit_2[axis] - hit_1[axis], time_2 - time_1)
+                    .to_integer()
+                    .expect("The rock should move a whole distance each nanosecond");
+                hit_1[axis] - time_1 * velocity
+            })
+            .sum::<i128>() as usize
     }
 }
 
+fn to_i128(point: Point3D) -> [i128; 3] {
+    [point.x as i128, point.y as i128, point.z as i128]
+}
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -18,11 +18,7 @@ peg::parser! { pub grammar day24_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<(Point3D, Point3D)>, AOCFileOrParseError> {
-    if let Ok(ret) = day24_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day24_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -1,99 +1,66 @@
 mod parser;
 use crate::parser::parse_data;
-use aoc_helpers::{hash_utils::HashVec, read_input_file, AOCCalculator, AOCFileOrParseError};
-use std::collections::HashSet;
-
-fn build_map(connections: &[(String, String)]) -> HashVec<String, String> {
-    let mut ret = HashVec::new();
-    for (left, right) in connections.iter() {
-        ret.push(left.to_string(), right.to_string());
-        ret.push(right.to_string(), left.to_string());
-    }
-    ret
-}
+use aoc_helpers::{
+    min_cut::{minimum_cut, Cut},
+    read_input_file, AOCCalculator, AOCFileOrParseError, Answer,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Day25 {
     connections: Vec<(String, String)>,
-    connections_map: HashVec<String, String>,
 }
 
 impl AOCCalculator for Day25 {
     fn new(filename: &str) -> Result<Day25, AOCFileOrParseError> {
-        let connections = parse_data(&read_input_file(filename)?)?;
         Ok(Day25 {
-            connections_map: build_map(&connections),
-            connections,
+            connections: parse_data(&read_input_file(filename)?)?,
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
-    }
-}
-
-impl Day25 {
-    fn how_many_neighbours_in_set(&self, set: &HashSet<String>, node: &str) -> usize {
-        HashSet::from_iter(self.connections_map.get(&node.to_string()).iter().cloned())
-            .difference(set)
-            .count()
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
     }
 
-    fn get_groups(&self) -> HashSet<String> {
-        let mut others = self.all_components();
-        while others
-            .iter()
-            .map(|node| self.how_many_neighbours_in_set(&others, node))
-            .sum::<usize>()
-            != 3
-        {
-            let max = others
-                .iter()
-                .max_by(|left, right| {
-                    self.how_many_neighbours_in_set(&others, left)
-                        .cmp(&self.how_many_neighbours_in_set(&others, right))
-                })
-                .unwrap()
-                .clone();
-            others.remove(&max);
-        }
-        others
+    fn part_b(&self) -> Answer {
+        Answer::None
     }
+}
 
-    fn all_components(&self) -> HashSet<String> {
-        let mut ret = HashSet::new();
-        for (left, right) in self.connections.iter() {
-            ret.insert(left.clone());
-            ret.insert(right.clone());
-        }
-        ret
+impl Day25 {
+    fn find_cut(&self) -> Cut<String> {
+        minimum_cut(self.connections.iter().cloned()).expect("Should be more than one component")
     }
 
     fn calculate_day_a(&self) -> usize {
-        let groups = self.get_groups();
-        let first = self.all_components().len() - groups.len();
-        let second = groups.len();
-        println!(
-            "Got group sizes {}, {} and {}, giving total {}",
-            self.all_components().len(),
-            first,
-            second,
-            first * second
-        );
+        let (first, second) = self.find_cut().sizes();
         first * second
     }
-
-    fn calculate_day_b(&self) -> usize {
-        0
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helpers::union_find::UnionFind;
     use pretty_assertions::assert_eq;
 
+    /// Removing the cut's edges has to leave exactly the 2 groups it says it found
+    fn assert_cut_splits_graph(day25: &Day25) {
+        let cut = day25.find_cut();
+        assert_eq!(3, cut.edges.len());
+        let mut groups: UnionFind<String> = UnionFind::new();
+        for (a, b) in &day25.connections {
+            groups.insert(a.clone());
+            groups.insert(b.clone());
+            if !cut.edges.contains(&(a.clone(), b.clone())) {
+                groups.union(a, b);
+            }
+        }
+        let mut actual = groups.component_sizes();
+        actual.sort();
+        let (first, second) = cut.sizes();
+        assert_eq!(vec![first.min(second), first.max(second)], actual);
+    }
+
     #[test]
     fn test_calculate_day_a() {
         let day25 = Day25::new("data/test_data.txt").unwrap();
@@ -103,27 +70,28 @@ mod tests {
     }
 
     #[test]
-    fn test_calculate_day_b() {
+    fn test_find_cut() {
         let day25 = Day25::new("data/test_data.txt").unwrap();
-        let expected = 0;
-        let actual = day25.calculate_day_b();
+        let mut actual: Vec<_> = day25
+            .find_cut()
+            .edges
+            .into_iter()
+            .map(|(a, b)| if a < b { (a, b) } else { (b, a) })
+            .collect();
+        actual.sort();
+        let expected: Vec<_> = [("bvb", "cmg"), ("hfx", "pzl"), ("jqt", "nvd")]
+            .iter()
+            .map(|(a, b)| (a.to_string(), b.to_string()))
+            .collect();
         assert_eq!(expected, actual);
+        assert_cut_splits_graph(&day25);
     }
 
     #[test]
     fn test_real_input_calculate_day_a() {
         let day25 = Day25::new("data/input_data.txt").unwrap();
-        let expected = 0;
+        assert_cut_splits_graph(&day25);
         let actual = day25.calculate_day_a();
         assert!(actual < 554840, "answer of {} is too high", actual);
-        assert_eq!(expected, actual);
-    }
-
-    #[test]
-    fn test_real_input_calculate_day_b() {
-        let day25 = Day25::new("data/input_data.txt").unwrap();
-        let expected = 0;
-        let actual = day25.calculate_day_b();
-        assert_eq!(expected, actual);
     }
 }
@@ -21,11 +21,7 @@ peg::parser! { pub grammar day25_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<(String, String)>, AOCFileOrParseError> {
-    if let Ok(ret) = day25_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day25_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -24,11 +24,7 @@ peg::parser! { pub grammar day3_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Schematic, AOCFileOrParseError> {
-    if let Ok(ret) = day3_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day3_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -1,13 +1,13 @@
-mod converter;
 mod parser;
 use crate::parser::parse_data;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
-use converter::ConverterMap;
-use std::ops::Range;
+use aoc_helpers::{
+    interval::{IntervalSet, PiecewiseMap},
+    read_input_file, AOCCalculator, AOCFileOrParseError, Answer,
+};
 
 pub struct Day5 {
     seeds: Vec<usize>,
-    converter_maps: Vec<ConverterMap>,
+    converter_maps: Vec<PiecewiseMap<usize>>,
 }
 
 impl AOCCalculator for Day5 {
@@ -19,9 +19,12 @@ impl AOCCalculator for Day5 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -29,15 +32,13 @@ impl Day5 {
     fn full_convert(&self, input: usize) -> usize {
         self.converter_maps
             .iter()
-            .fold(input, |x, converter| converter.convert(x))
+            .fold(input, |x, converter| converter.map(x))
     }
 
-    fn full_convert_range(&self, input: Vec<Range<usize>>) -> Vec<Range<usize>> {
-        let ret = self
-            .converter_maps
+    fn full_convert_range(&self, input: IntervalSet<usize>) -> IntervalSet<usize> {
+        self.converter_maps
             .iter()
-            .fold(input, |x, converter| converter.convert_ranges(&x));
-        ret
+            .fold(input, |x, converter| converter.map_set(&x))
     }
 
     fn calculate_day_a(&self) -> usize {
@@ -48,7 +49,7 @@ impl Day5 {
             .unwrap_or(0)
     }
 
-    fn get_seeds_as_range(&self) -> Vec<Range<usize>> {
+    fn get_seeds_as_range(&self) -> IntervalSet<usize> {
         self.seeds[..]
             .chunks(2)
             .map(|chunk| chunk[0]..(chunk[0] + chunk[1]))
@@ -57,8 +58,6 @@ impl Day5 {
 
     fn calculate_day_b(&self) -> usize {
         self.full_convert_range(self.get_seeds_as_range())
-            .iter()
-            .map(|range| range.start)
             .min()
             .unwrap_or(0)
     }
@@ -1,6 +1,7 @@
 extern crate peg;
-use crate::converter::{Converter, ConverterMap};
+use aoc_helpers::interval::PiecewiseMap;
 use aoc_helpers::AOCFileOrParseError;
+use std::ops::Range;
 
 peg::parser! { pub grammar day5_parser() for str {
     rule number() -> usize
@@ -9,26 +10,24 @@ peg::parser! { pub grammar day5_parser() for str {
         = "seeds:" " "* seeds:number() ++ (" "+) " "* { seeds }
     rule name() -> &'input str
         = name:$(['a'..='z'|'A'..='Z']+) { name }
-    rule converter() -> Converter
+    rule converter() -> (Range<usize>, usize)
         = output_start:number() " "+  input_start:number() " "+ range:number() {
-            Converter::new(output_start, input_start, range)
+            (input_start..input_start + range, output_start)
     }
-    rule converter_map() -> ConverterMap
+    rule converter_map() -> PiecewiseMap<usize>
         = name() "-to-" name() " map:\n" converters:converter() ++ ("\n" +) {
-            ConverterMap::new( converters )
+            converters.into_iter().collect()
         }
-    pub rule parse() -> (Vec<usize>, Vec<ConverterMap>)
+    pub rule parse() -> (Vec<usize>, Vec<PiecewiseMap<usize>>)
         = seeds:seeds() "\n"+ converter_maps:converter_map() ++ ("\n"+) "\n" * {
             (seeds, converter_maps)
         }
 }}
 
-pub fn parse_data(input: &str) -> Result<(Vec<usize>, Vec<ConverterMap>), AOCFileOrParseError> {
-    if let Ok(ret) = day5_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+pub fn parse_data(
+    input: &str,
+) -> Result<(Vec<usize>, Vec<PiecewiseMap<usize>>), AOCFileOrParseError> {
+    day5_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -15,11 +15,7 @@ peg::parser! { pub grammar day6_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<(usize, usize)>, AOCFileOrParseError> {
-    if let Ok(ret) = day6_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day6_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -2,7 +2,7 @@ mod parser;
 mod poker_hand;
 use crate::parser::parse_data;
 use crate::poker_hand::PokerHand;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 
 pub struct Day7 {
     bets: Vec<(PokerHand, usize)>,
@@ -15,9 +15,12 @@ impl AOCCalculator for Day7 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -52,11 +52,7 @@ peg::parser! { pub grammar day7_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<(PokerHand, usize)>, AOCFileOrParseError> {
-    if let Ok(ret) = day7_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day7_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
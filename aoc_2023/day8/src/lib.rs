@@ -3,8 +3,8 @@ mod parser;
 use std::collections::HashMap;
 
 use crate::parser::parse_data;
-use aoc_helpers::modular_math::least_common_multiple;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::modular_math::least_common_multiple_of;
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 use direction::Direction;
 use parser::NodeMap;
 
@@ -22,9 +22,12 @@ impl AOCCalculator for Day8 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -127,12 +130,8 @@ impl Day8 {
             .cloned()
             .collect();
         let cycle_lengths = self.get_cycle_lengths_for_day_b_nodes(&starter_nodes);
-        let lcm = cycle_lengths
-            .iter()
-            .copied()
-            .reduce(least_common_multiple)
-            .unwrap();
-        lcm
+        least_common_multiple_of(cycle_lengths.iter().map(|&length| length as u128))
+            .expect("The ghosts should all line up before overflowing") as usize
     }
 }
 
@@ -34,11 +34,7 @@ peg::parser! { pub grammar day8_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<(Vec<Direction>, NodeMap), AOCFileOrParseError> {
-    if let Ok(ret) = day8_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day8_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -3,59 +3,44 @@ use std::collections::HashSet;
 
 use crate::parser::parse_data;
 use aoc_helpers::{
-    point2d::{Neighbours, Point2D},
-    read_input_file, AOCCalculator, AOCFileOrParseError,
+    grid::Grid, point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError, Answer,
 };
-use itertools::Itertools;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Day10 {
-    topography: Vec<Vec<usize>>,
+    topography: Grid<usize>,
 }
 
 impl AOCCalculator for Day10 {
     fn new(filename: &str) -> Result<Day10, AOCFileOrParseError> {
         Ok(Day10 {
-            topography: parse_data(&read_input_file(filename)?)?,
+            topography: Grid::from_rows(parse_data(&read_input_file(filename)?)?)?,
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
 impl Day10 {
-    fn within_limit(&self, point: &Point2D) -> bool {
-        (0..self.topography.len()).contains(&(point.y as usize))
-            && (0..self.topography[0].len()).contains(&(point.x as usize))
-    }
     fn get_at_point(&self, point: &Point2D) -> Option<usize> {
-        if self.within_limit(point) {
-            Some(self.topography[point.y as usize][point.x as usize])
-        } else {
-            None
-        }
-    }
-    fn iter_all_points(&self) -> Box<dyn Iterator<Item = Point2D>> {
-        Box::new(
-            (0..self.topography.len())
-                .cartesian_product(0..self.topography[0].len())
-                .map(|(y, x)| Point2D::from_usize(x, y)),
-        )
+        self.topography.get(point).copied()
     }
 
     fn explore_count_a(&self, point: &Point2D) -> HashSet<Point2D> {
         if self.get_at_point(point) == Some(9) {
             HashSet::from([*point])
         } else {
-            point
-                .get_neighbours()
-                .iter()
+            self.topography
+                .neighbours(point)
                 .map(
-                    |neighbour| match (self.get_at_point(point), self.get_at_point(neighbour)) {
-                        (Some(a), Some(b)) if b == a + 1 => self.explore_count_a(neighbour),
+                    |neighbour| match (self.get_at_point(point), self.get_at_point(&neighbour)) {
+                        (Some(a), Some(b)) if b == a + 1 => self.explore_count_a(&neighbour),
                         _ => HashSet::new(),
                     },
                 )
@@ -69,8 +54,8 @@ impl Day10 {
     }
 
     fn calculate_day_a(&self) -> usize {
-        self.iter_all_points()
-            .filter(|p| self.get_at_point(p) == Some(0))
+        self.topography
+            .find_all(|&height| height == 0)
             .map(|p| self.explore_count_a(&p).len())
             .sum()
     }
@@ -79,12 +64,11 @@ impl Day10 {
         if self.get_at_point(point) == Some(9) {
             1
         } else {
-            point
-                .get_neighbours()
-                .iter()
+            self.topography
+                .neighbours(point)
                 .map(
-                    |neighbour| match (self.get_at_point(point), self.get_at_point(neighbour)) {
-                        (Some(a), Some(b)) if b == a + 1 => self.explore_count_b(neighbour),
+                    |neighbour| match (self.get_at_point(point), self.get_at_point(&neighbour)) {
+                        (Some(a), Some(b)) if b == a + 1 => self.explore_count_b(&neighbour),
                         _ => 0,
                     },
                 )
@@ -93,8 +77,8 @@ impl Day10 {
     }
 
     fn calculate_day_b(&self) -> usize {
-        self.iter_all_points()
-            .filter(|p| self.get_at_point(p) == Some(0))
+        self.topography
+            .find_all(|&height| height == 0)
             .map(|p| self.explore_count_b(&p))
             .sum::<usize>()
     }
@@ -1,10 +1,13 @@
 mod parser;
-use std::collections::{HashSet, VecDeque};
+use std::collections::HashSet;
 
 use crate::parser::parse_data;
 use aoc_helpers::{
+    direction::Direction4,
     point2d::{Neighbours, Point2D},
-    read_input_file, AOCCalculator, AOCFileOrParseError,
+    read_input_file,
+    union_find::UnionFind,
+    AOCCalculator, AOCFileOrParseError, Answer,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,9 +22,12 @@ impl AOCCalculator for Day12 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -39,45 +45,26 @@ impl Day12 {
     }
 
     fn separate_garden(&self) -> Vec<HashSet<Point2D>> {
-        let mut ret: Vec<HashSet<Point2D>> = Vec::new();
-        let mut explore = VecDeque::new();
-        let mut explored = HashSet::new();
-        explore.push_front(Point2D::from_usize(0, 0));
-        while let Some(plant_location) = explore.pop_front() {
-            if explored.contains(&plant_location) {
-                continue;
-            }
-            explored.insert(plant_location);
-            if let Some(plant_tile) = self.get_plant(&plant_location) {
-                let neighbours: HashSet<Point2D> =
-                    HashSet::from_iter(plant_location.get_neighbours());
-                let plot_index = ret
-                    .iter()
-                    .enumerate()
-                    .find(|(_, plot)| {
-                        neighbours.iter().any(|neighbour| {
-                            plot.contains(neighbour)
-                                && self.get_plant(neighbour) == Some(plant_tile)
-                        })
-                    })
-                    .map(|(index, _)| index);
-                if let Some(plot_index) = plot_index {
-                    ret[plot_index].insert(plant_location);
-                } else {
-                    ret.push(HashSet::from([plant_location]));
-                }
-                for neighbour in plant_location.get_neighbours().into_iter() {
-                    if self.within_bounds(&neighbour) {
-                        if self.get_plant(&neighbour) == Some(plant_tile) {
-                            explore.push_front(neighbour);
-                        } else {
-                            explore.push_back(neighbour);
-                        }
-                    }
+        let mut plots = UnionFind::new();
+        for plant_location in
+            Point2D::iterate_x_y_usize(0..self.garden[0].len(), 0..self.garden.len())
+        {
+            plots.insert(plant_location);
+            let plant_tile = self.get_plant(&plant_location);
+            // Only looking right and down, since left and up joined us when they were looked at
+            for neighbour in [Direction4::Right, Direction4::Down]
+                .map(|direction| plant_location + direction.as_point())
+            {
+                if self.get_plant(&neighbour) == plant_tile {
+                    plots.union(&plant_location, &neighbour);
                 }
             }
         }
-        ret
+        plots
+            .components()
+            .into_iter()
+            .map(HashSet::from_iter)
+            .collect()
     }
 
     fn calculate_perimeter(&self, plot: &HashSet<Point2D>) -> usize {
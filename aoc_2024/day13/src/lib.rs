@@ -1,6 +1,7 @@
 mod parser;
 use crate::parser::parse_data;
-use aoc_helpers::{point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::linear_algebra::cramer;
+use aoc_helpers::{point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 use parser::ClawMachine;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -15,9 +16,12 @@ impl AOCCalculator for Day13 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -52,22 +56,6 @@ impl Day13 {
         ret
     }
 
-    fn gaussian_elimination(&self, mut matrix: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
-        for pivot in 0..2 {
-            for point in (pivot..matrix[pivot].len()).rev() {
-                matrix[pivot][point] /= matrix[pivot][pivot];
-            }
-            for other in 0..2 {
-                if other != pivot {
-                    for point in (pivot..matrix[other].len()).rev() {
-                        matrix[other][point] -= matrix[pivot][point] * matrix[other][pivot]
-                    }
-                }
-            }
-        }
-        matrix
-    }
-
     fn calculate_prize_button_calls_day_b(
         &self,
         claw_machine: &ClawMachine,
@@ -82,30 +70,21 @@ impl Day13 {
         // and minimise for 3*a + b == answer
         //
         // so 2 formula and 2 unknowns, a and b
-        // gaussian elimination:
+        // which is small enough for Cramer's rule:
         // [
         //   x_a   x_b  | x_c
         //   y_a   y_b  | y_c
         // ] and minimisation is a red herring
-        let x_a = claw_machine.0.x as f64;
-        let x_b = claw_machine.1.x as f64;
-        let x_c = (claw_machine.2 + *offset).x as f64;
-        let y_a = claw_machine.0.y as f64;
-        let y_b = claw_machine.1.y as f64;
-        let y_c = (claw_machine.2 + *offset).y as f64;
-        let matrix = self.gaussian_elimination(vec![vec![x_a, x_b, x_c], vec![y_a, y_b, y_c]]);
-        let a = matrix[0][2];
-        let b = matrix[1][2];
-        if (a.round() - a).abs() < 0.01
-            && (b.round() - b).abs() < 0.01
-            && a >= 0.
-            && b >= 0.
-            && (limit.is_none()
-                || limit.is_some()
-                    && a.round() as isize <= limit.unwrap()
-                    && b.round() as isize <= limit.unwrap())
-        {
-            Some((a.round() as isize, b.round() as isize))
+        let target = claw_machine.2 + *offset;
+        let matrix = [
+            vec![claw_machine.0.x as i128, claw_machine.1.x as i128],
+            vec![claw_machine.0.y as i128, claw_machine.1.y as i128],
+        ];
+        let presses = cramer(&matrix, &[target.x as i128, target.y as i128])?;
+        let a = presses[0].to_integer()? as isize;
+        let b = presses[1].to_integer()? as isize;
+        if a >= 0 && b >= 0 && limit.is_none_or(|limit| a <= limit && b <= limit) {
+            Some((a, b))
         } else {
             None
         }
@@ -16,11 +16,7 @@ peg::parser! { pub grammar day13_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<ClawMachine>, AOCFileOrParseError> {
-    if let Ok(ret) = day13_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day13_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -2,7 +2,10 @@ mod parser;
 use std::collections::HashSet;
 
 use crate::parser::parse_data;
-use aoc_helpers::{hash_utils::FromVec, point2d::Point2D, read_input_file, AOCFileOrParseError};
+use aoc_helpers::{
+    hash_utils::FromVec, point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError,
+    Answer,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Day14 {
@@ -11,8 +14,26 @@ pub struct Day14 {
     height: isize,
 }
 
+impl AOCCalculator for Day14 {
+    fn new(filename: &str) -> Result<Day14, AOCFileOrParseError> {
+        Day14::new_with_size(filename, 101, 103)
+    }
+
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
+    }
+}
+
 impl Day14 {
-    pub fn new(filename: &str, width: isize, height: isize) -> Result<Day14, AOCFileOrParseError> {
+    pub fn new_with_size(
+        filename: &str,
+        width: isize,
+        height: isize,
+    ) -> Result<Day14, AOCFileOrParseError> {
         let robots = parse_data(&read_input_file(filename)?)?;
         Ok(Day14 {
             robots: HashSet::from_vec(&robots),
@@ -20,11 +41,6 @@ impl Day14 {
             height,
         })
     }
-
-    pub fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
-    }
 }
 
 impl Day14 {
@@ -121,7 +137,7 @@ mod tests {
 
     #[test]
     fn test_calculate_day_a() {
-        let day14 = Day14::new("data/test_data.txt", 11, 7).unwrap();
+        let day14 = Day14::new_with_size("data/test_data.txt", 11, 7).unwrap();
         let expected = 12;
         let actual = day14.calculate_day_a();
         assert_eq!(expected, actual);
@@ -129,7 +145,7 @@ mod tests {
 
     #[test]
     fn test_real_input_calculate_day_a() {
-        let day14 = Day14::new("data/input_data.txt", 101, 103).unwrap();
+        let day14 = Day14::new("data/input_data.txt").unwrap();
         let expected = 211692000;
         let actual = day14.calculate_day_a();
         assert_eq!(expected, actual);
@@ -137,7 +153,7 @@ mod tests {
 
     #[test]
     fn test_real_input_calculate_day_b() {
-        let day14 = Day14::new("data/input_data.txt", 101, 103).unwrap();
+        let day14 = Day14::new("data/input_data.txt").unwrap();
         let expected = 6587;
         let actual = day14.calculate_day_b();
         assert_eq!(expected, actual);
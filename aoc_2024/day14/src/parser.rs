@@ -17,11 +17,7 @@ peg::parser! { pub grammar day14_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<(Point2D, Point2D)>, AOCFileOrParseError> {
-    if let Ok(ret) = day14_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day14_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
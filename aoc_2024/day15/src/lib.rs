@@ -4,13 +4,14 @@ mod types;
 use std::collections::HashSet;
 
 use crate::parser::parse_data;
-use aoc_helpers::{point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError};
-use types::{Direction, RobotMap, WideTile};
+use aoc_helpers::direction::Direction4;
+use aoc_helpers::{point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
+use types::{RobotMap, WideTile};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Day15 {
     robot_map: RobotMap,
-    directions: Vec<Direction>,
+    directions: Vec<Direction4>,
 }
 
 impl AOCCalculator for Day15 {
@@ -22,9 +23,12 @@ impl AOCCalculator for Day15 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -59,7 +63,7 @@ impl Day15 {
         }
     }
 
-    fn try_move_character(&mut self, direction: Direction) {
+    fn try_move_character(&mut self, direction: Direction4) {
         let character = self.robot_map.character;
         let (moved, items_to_move) = self.try_move_point(&character, &direction.as_point());
         if moved {
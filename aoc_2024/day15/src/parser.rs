@@ -1,5 +1,6 @@
 extern crate peg;
-use crate::types::{Direction, MapTile};
+use crate::types::MapTile;
+use aoc_helpers::direction::Direction4;
 use aoc_helpers::AOCFileOrParseError;
 
 peg::parser! { pub grammar day15_parser() for str {
@@ -19,28 +20,26 @@ peg::parser! { pub grammar day15_parser() for str {
         = map_line:map_tile() ++ "" { map_line }
     rule map() -> Vec<Vec<MapTile>>
         = map:map_line() ++ "\n" { map }
-    rule up() -> Direction
-        = "^" { Direction::Up }
-    rule down() -> Direction
-        = "v" { Direction::Down }
-    rule left() -> Direction
-        = "<" { Direction::Left }
-    rule right() -> Direction
-        = ">" { Direction::Right }
-    rule direction() -> Direction
+    rule up() -> Direction4
+        = "^" { Direction4::Up }
+    rule down() -> Direction4
+        = "v" { Direction4::Down }
+    rule left() -> Direction4
+        = "<" { Direction4::Left }
+    rule right() -> Direction4
+        = ">" { Direction4::Right }
+    rule direction() -> Direction4
         = direction:(up() / down() / left() / right()) { direction }
-    rule directions() -> Vec<Direction>
+    rule directions() -> Vec<Direction4>
         = directions:direction() ++ ("\n"?) { directions }
-    pub rule parse() -> (Vec<Vec<MapTile>>, Vec<Direction>)
+    pub rule parse() -> (Vec<Vec<MapTile>>, Vec<Direction4>)
         = map:map() "\n"* directions:directions() "\n"* { (map, directions) }
 }}
 
-pub fn parse_data(input: &str) -> Result<(Vec<Vec<MapTile>>, Vec<Direction>), AOCFileOrParseError> {
-    if let Ok(ret) = day15_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+pub fn parse_data(
+    input: &str,
+) -> Result<(Vec<Vec<MapTile>>, Vec<Direction4>), AOCFileOrParseError> {
+    day15_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -137,21 +136,21 @@ mod test {
             ],
         ];
         let expected_directions = vec![
-            Direction::Left,
-            Direction::Up,
-            Direction::Up,
-            Direction::Right,
-            Direction::Right,
-            Direction::Right,
-            Direction::Down,
-            Direction::Down,
-            Direction::Left,
-            Direction::Down,
-            Direction::Right,
-            Direction::Right,
-            Direction::Down,
-            Direction::Left,
-            Direction::Left,
+            Direction4::Left,
+            Direction4::Up,
+            Direction4::Up,
+            Direction4::Right,
+            Direction4::Right,
+            Direction4::Right,
+            Direction4::Down,
+            Direction4::Down,
+            Direction4::Left,
+            Direction4::Down,
+            Direction4::Right,
+            Direction4::Right,
+            Direction4::Down,
+            Direction4::Left,
+            Direction4::Left,
         ];
         assert_eq!(expected_map, actual_map);
         assert_eq!(expected_directions, actual_directions);
@@ -11,14 +11,6 @@ pub enum MapTile {
     Empty,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RobotMap {
     pub walls: HashSet<WideTile>,
@@ -181,14 +173,3 @@ impl Display for RobotMap {
         write!(f, "{}", ret)
     }
 }
-
-impl Direction {
-    pub fn as_point(&self) -> Point2D {
-        match self {
-            Direction::Up => Point2D { x: 0, y: -1 },
-            Direction::Down => Point2D { x: 0, y: 1 },
-            Direction::Left => Point2D { x: -1, y: 0 },
-            Direction::Right => Point2D { x: 1, y: 0 },
-        }
-    }
-}
@@ -1,161 +1,94 @@
 mod parser;
-use std::{
-    cmp::Reverse,
-    collections::{BinaryHeap, HashMap, HashSet},
-};
-
 use crate::parser::parse_data;
 use aoc_helpers::{
+    grid::Grid,
     point2d::{Neighbours, Point2D},
-    read_input_file, AOCCalculator, AOCFileOrParseError,
+    read_input_file,
+    search::{dijkstra_all, AllShortestPaths},
+    AOCCalculator, AOCFileOrParseError, Answer,
 };
-use itertools::Itertools;
 use parser::MazeTile;
+use std::collections::HashSet;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Day16 {
-    maze: Vec<Vec<MazeTile>>,
+    maze: Grid<MazeTile>,
     start: Point2D,
     end: Point2D,
 }
 
 impl AOCCalculator for Day16 {
     fn new(filename: &str) -> Result<Day16, AOCFileOrParseError> {
-        let maze = parse_data(&read_input_file(filename)?)?;
-        let start_end: Vec<Point2D> = (0..maze.len())
-            .cartesian_product(0..maze[0].len())
-            .filter(|(y, x)| maze[*y][*x] == MazeTile::Start || maze[*y][*x] == MazeTile::End)
-            .map(|(y, x)| Point2D::from_usize(x, y))
-            .collect();
-        let start = start_end
-            .iter()
-            .find(|p| maze[p.y as usize][p.x as usize] == MazeTile::Start);
-        let end = start_end
-            .iter()
-            .find(|p| maze[p.y as usize][p.x as usize] == MazeTile::End);
+        let maze = Grid::from_rows(parse_data(&read_input_file(filename)?)?)?;
+        let start = maze.find(|&tile| tile == MazeTile::Start);
+        let end = maze.find(|&tile| tile == MazeTile::End);
 
         match (start, end) {
-            (Some(start), Some(end)) => Ok(Day16 {
-                maze,
-                start: *start,
-                end: *end,
-            }),
-            _ => Err(AOCFileOrParseError),
+            (Some(start), Some(end)) => Ok(Day16 { maze, start, end }),
+            _ => Err(AOCFileOrParseError::InvalidInput(
+                "maze needs both a start and an end".to_string(),
+            )),
         }
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
 impl Day16 {
     fn can_move(&self, point: &Point2D) -> bool {
-        (0..(self.maze.len() as isize)).contains(&point.y)
-            && (0..(self.maze[0].len() as isize)).contains(&point.x)
-            && [MazeTile::Room, MazeTile::End]
-                .contains(&self.maze[point.y as usize][point.x as usize])
+        self.maze
+            .get(point)
+            .is_some_and(|tile| [MazeTile::Room, MazeTile::End].contains(tile))
     }
 
-    fn explore(&self) -> (usize, usize) {
-        let mut exploration_heap = BinaryHeap::new();
-        exploration_heap.push(Reverse((0, vec![self.start], Point2D::from_usize(1, 0))));
-        let mut best_score: HashMap<Point2D, usize> = HashMap::new();
-        let mut nodes_on_best_path: HashSet<Point2D> = HashSet::new();
-        while let Some(Reverse((score, path, direction))) = exploration_heap.pop() {
-            let location = path[path.len() - 1];
-            let this_best_score = best_score.get(&location);
-            if this_best_score.is_some_and(|best_score| score <= *best_score)
-                || this_best_score.is_none()
-            {
-                if !best_score.contains_key(&location)
-                    || best_score
-                        .get(&location)
-                        .is_some_and(|best_score| score < *best_score)
-                {
-                    best_score.insert(location, score);
-                }
-
-                for neighbour in location.get_neighbours().into_iter() {
-                    if path.contains(&neighbour) {
-                        continue;
-                    }
-                    if neighbour == location + direction && self.can_move(&neighbour) {
-                        let mut new_path = path.clone();
-                        new_path.push(neighbour);
-                        exploration_heap.push(Reverse((score + 1, new_path, neighbour - location)));
-                    } else if neighbour != location - direction && self.can_move(&neighbour) {
-                        let mut new_path = path.clone();
-                        new_path.push(neighbour);
-                        exploration_heap.push(Reverse((
-                            score + 1001,
-                            new_path,
-                            neighbour - location,
-                        )));
-                    }
-                }
-            }
-        }
-        exploration_heap.push(Reverse((0, vec![self.start], Point2D::from_usize(1, 0))));
-
-        while let Some(Reverse((score, path, direction))) = exploration_heap.pop() {
-            let location = path[path.len() - 1];
-            let this_best_score = best_score.get(&location);
-            if this_best_score.is_some_and(|best_score| score <= *best_score + 1000)
-                || this_best_score.is_none()
-            {
-                if location == self.end {
-                    let best_end_score = best_score
-                        .iter()
-                        .filter(|(k, _)| **k == self.end)
-                        .map(|(_, v)| v)
-                        .min();
-                    if best_end_score.is_some_and(|best_end_score| score < *best_end_score) {
-                        nodes_on_best_path.clear();
-                    }
-                    if best_end_score.is_some_and(|best_end_score| score <= *best_end_score)
-                        || best_end_score.is_none()
-                    {
-                        nodes_on_best_path.extend(path.iter().clone());
-                    }
-                }
-                for neighbour in location.get_neighbours().into_iter() {
-                    if path.contains(&neighbour) {
-                        continue;
-                    }
-                    if neighbour == location + direction && self.can_move(&neighbour) {
-                        let mut new_path = path.clone();
-                        new_path.push(neighbour);
-                        exploration_heap.push(Reverse((score + 1, new_path, neighbour - location)));
-                    } else if neighbour != location - direction && self.can_move(&neighbour) {
-                        let mut new_path = path.clone();
-                        new_path.push(neighbour);
-                        exploration_heap.push(Reverse((
-                            score + 1001,
-                            new_path,
-                            neighbour - location,
-                        )));
-                    }
-                }
-            }
-        }
-        (
-            *best_score
-                .iter()
-                .find(|(k, _)| **k == self.end)
-                .expect("Should get an answer")
-                .1,
-            nodes_on_best_path.len(),
+    /// Each state is a location and the direction being faced. Stepping forward costs 1, and
+    /// turning to step left or right costs another 1000.
+    fn moves(
+        &self,
+        &(location, direction): &(Point2D, Point2D),
+    ) -> Vec<((Point2D, Point2D), usize)> {
+        location
+            .get_neighbours()
+            .into_iter()
+            .filter(|neighbour| *neighbour != location - direction && self.can_move(neighbour))
+            .map(|neighbour| {
+                let cost = if neighbour == location + direction {
+                    1
+                } else {
+                    1001
+                };
+                ((neighbour, neighbour - location), cost)
+            })
+            .collect()
+    }
+
+    fn explore(&self) -> AllShortestPaths<(Point2D, Point2D)> {
+        dijkstra_all(
+            (self.start, Point2D::from_usize(1, 0)),
+            |state| self.moves(state),
+            |(location, _)| *location == self.end,
         )
+        .expect("Should get an answer")
     }
 
     fn calculate_day_a(&self) -> usize {
-        self.explore().0
+        self.explore().cost
     }
 
     fn calculate_day_b(&self) -> usize {
-        self.explore().1
+        let tiles: HashSet<Point2D> = self
+            .explore()
+            .on_any_path
+            .into_iter()
+            .map(|(location, _)| location)
+            .collect();
+        tiles.len()
     }
 }
 
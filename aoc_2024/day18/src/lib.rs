@@ -1,13 +1,12 @@
 mod parser;
-use std::{
-    cmp::Reverse,
-    collections::{BinaryHeap, HashMap, HashSet},
-};
+use std::collections::HashSet;
 
 use crate::parser::parse_data;
 use aoc_helpers::{
     point2d::{Neighbours, Point2D},
-    read_input_file, AOCCalculator, AOCFileOrParseError,
+    read_input_file,
+    search::bfs,
+    AOCCalculator, AOCFileOrParseError, Answer,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -22,17 +21,13 @@ impl AOCCalculator for Day18 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!(
-            "{}a answer is {:?}",
-            name,
-            self.calculate_day_a(71, 71, 1024)
-        );
-        println!(
-            "{}b answer is {:?}",
-            name,
-            self.calculate_day_b(71, 71, 1024)
-        );
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a(71, 71, 1024).into()
+    }
+
+    fn part_b(&self) -> Answer {
+        let wall = self.calculate_day_b(71, 71, 1024);
+        format!("{},{}", wall.x, wall.y).into()
     }
 }
 
@@ -42,28 +37,22 @@ impl Day18 {
     }
 
     fn explore(&self, width: usize, height: usize, num_walls: usize) -> Option<usize> {
-        let mut explore_queue = BinaryHeap::new();
-        explore_queue.push(Reverse((0, Point2D::from_usize(0, 0))));
-        let mut best_value: HashMap<Point2D, usize> = HashMap::new();
         let walls: HashSet<Point2D> = HashSet::from_iter(self.walls[0..num_walls].iter().cloned());
-        while let Some(Reverse((score, location))) = explore_queue.pop() {
-            if !best_value.contains_key(&location)
-                || best_value
-                    .get(&location)
-                    .is_some_and(|best_score| score < *best_score)
-            {
-                best_value.insert(location, score);
-                for neighbour in location.get_neighbours().into_iter() {
-                    if self.within_bounds(&neighbour, width, height) && !walls.contains(&neighbour)
-                    {
-                        explore_queue.push(Reverse((score + 1, neighbour)));
-                    }
-                }
-            }
-        }
-        best_value
-            .get(&Point2D::from_usize(width - 1, height - 1))
-            .copied()
+        let exit = Point2D::from_usize(width - 1, height - 1);
+        bfs(
+            Point2D::from_usize(0, 0),
+            |location| {
+                location
+                    .get_neighbours()
+                    .into_iter()
+                    .filter(|neighbour| {
+                        self.within_bounds(neighbour, width, height) && !walls.contains(neighbour)
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |location| *location == exit,
+        )
+        .map(|result| result.cost)
     }
 
     fn calculate_day_a(&self, width: usize, height: usize, num_walls: usize) -> usize {
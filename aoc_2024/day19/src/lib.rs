@@ -1,8 +1,8 @@
 mod parser;
-use std::collections::{HashMap, VecDeque};
+use std::collections::VecDeque;
 
 use crate::parser::parse_data;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{memo::Memo, read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Day19 {
@@ -16,9 +16,12 @@ impl AOCCalculator for Day19 {
         Ok(Day19 { patterns, desired })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -42,21 +45,17 @@ impl Day19 {
     }
 
     fn ways_to_end(&self, desired: &str) -> usize {
-        let mut ways_to_point: HashMap<usize, usize> = HashMap::new();
-        ways_to_point.insert(0, 1);
-        for i in 0..desired.len() {
-            let paths_here = *ways_to_point.get(&i).unwrap_or(&0);
-            if paths_here == 0 {
-                continue;
-            }
-            for pattern in self.patterns.iter() {
-                if desired[i..desired.len()].starts_with(pattern) {
-                    let next = i + pattern.len();
-                    *ways_to_point.entry(next).or_default() += paths_here;
-                }
+        Memo::new(|memo, &start: &usize| {
+            if start == desired.len() {
+                return 1;
             }
-        }
-        *ways_to_point.get(&(desired.len())).unwrap_or(&0)
+            self.patterns
+                .iter()
+                .filter(|pattern| desired[start..].starts_with(pattern.as_str()))
+                .map(|pattern| memo.get(start + pattern.len()))
+                .sum()
+        })
+        .get(0)
     }
 
     fn calculate_day_a(&self) -> usize {
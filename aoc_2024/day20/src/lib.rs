@@ -1,13 +1,12 @@
 mod parser;
-use std::{
-    cmp::Reverse,
-    collections::{BinaryHeap, HashMap, HashSet},
-};
+use std::collections::HashSet;
 
 use crate::parser::parse_data;
 use aoc_helpers::{
     point2d::{Neighbours, Point2D},
-    read_input_file, AOCCalculator, AOCFileOrParseError,
+    read_input_file,
+    search::bfs,
+    AOCCalculator, AOCFileOrParseError, Answer,
 };
 use itertools::Itertools;
 use parser::MazeTile;
@@ -24,9 +23,12 @@ impl AOCCalculator for Day20 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -59,7 +61,6 @@ impl Day20 {
     }
 
     fn find_shortest_path(&self) -> Vec<Point2D> {
-        // simple dijkstra style algorithm to find the shortest path
         let walls = self.build_walls();
         let start = self
             .all_points()
@@ -71,33 +72,18 @@ impl Day20 {
             .into_iter()
             .find(|point| self.get_at_point(point) == Some(MazeTile::End))
             .expect("There is an end");
-        let mut to_explore: BinaryHeap<Reverse<(usize, Vec<Point2D>)>> = BinaryHeap::new();
-        to_explore.push(Reverse((1, vec![start])));
-        let mut fastest_to_point: HashMap<Point2D, Vec<Point2D>> = HashMap::new();
-        while let Some(Reverse((distance, path))) = to_explore.pop() {
-            let current_point = path[path.len() - 1];
-            if !fastest_to_point.contains_key(&current_point)
-                || fastest_to_point
-                    .get(&current_point)
-                    .is_some_and(|fastest_distance| distance < fastest_distance.len())
-            {
-                fastest_to_point.insert(current_point, path.clone());
-                if current_point == end {
-                    continue;
-                }
-                for neighbour in current_point.get_neighbours().into_iter() {
-                    if !walls.contains(&neighbour) {
-                        let mut neighbour_path = path.clone();
-                        neighbour_path.push(neighbour);
-                        to_explore.push(Reverse((distance + 1, neighbour_path)));
-                    }
-                }
-            }
-        }
-        fastest_to_point
-            .get(&end)
-            .expect("We find a shortest path")
-            .clone()
+        bfs(
+            start,
+            |point| {
+                point
+                    .get_neighbours()
+                    .into_iter()
+                    .filter(|neighbour| !walls.contains(neighbour))
+            },
+            |point| point == &end,
+        )
+        .expect("We find a shortest path")
+        .path
     }
 
     fn explore_cheats(
@@ -154,6 +140,7 @@ impl Day20 {
 mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
+    use std::collections::HashMap;
 
     #[test]
     fn test_calculate_day_a() {
@@ -29,11 +29,7 @@ peg::parser! { pub grammar day20_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<Vec<MazeTile>>, AOCFileOrParseError> {
-    if let Ok(ret) = day20_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day20_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -9,11 +9,7 @@ peg::parser! { pub grammar day22_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<usize>, AOCFileOrParseError> {
-    if let Ok(ret) = day22_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day22_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
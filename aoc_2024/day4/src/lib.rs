@@ -1,45 +1,34 @@
 mod parser;
 use crate::parser::parse_data;
-use aoc_helpers::{point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{
+    grid::Grid, point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError, Answer,
+};
 use itertools::Itertools;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Day4 {
-    data: Vec<Vec<char>>,
+    data: Grid<char>,
 }
 
 impl AOCCalculator for Day4 {
     fn new(filename: &str) -> Result<Day4, AOCFileOrParseError> {
         Ok(Day4 {
-            data: parse_data(&read_input_file(filename)?)?,
+            data: Grid::from_rows(parse_data(&read_input_file(filename)?)?)?,
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
 impl Day4 {
     fn get_at_point(&self, point: &Point2D) -> Option<char> {
-        if point.y >= 0
-            && (point.y as usize) < self.data.len()
-            && point.x >= 0
-            && (point.x as usize) < self.data[point.y as usize].len()
-        {
-            Some(self.data[point.y as usize][point.x as usize])
-        } else {
-            None
-        }
-    }
-
-    fn iter_all_points(&self) -> Box<dyn Iterator<Item = Point2D>> {
-        Box::new(
-            (0..self.data.len())
-                .cartesian_product(0..self.data[0].len())
-                .map(|(y, x)| Point2D::from_usize(x, y)),
-        )
+        self.data.get(point).copied()
     }
 
     fn get_all_directions_a(&self) -> Box<dyn Iterator<Item = Vec<Point2D>>> {
@@ -78,7 +67,8 @@ impl Day4 {
     }
 
     fn calculate_day_a(&self) -> usize {
-        self.iter_all_points()
+        self.data
+            .points()
             .map(|point| {
                 self.get_all_directions_a()
                     .map(|direction| self.convert_line_to_chars(&point, &direction))
@@ -89,7 +79,8 @@ impl Day4 {
     }
 
     fn calculate_day_b(&self) -> usize {
-        self.iter_all_points()
+        self.data
+            .points()
             .map(|point| {
                 self.get_all_directions_b()
                     .iter()
@@ -1,6 +1,8 @@
 mod parser;
 use crate::parser::parse_data;
-use aoc_helpers::{hash_utils::FromVec, read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{
+    dag::Dag, hash_utils::FromVec, read_input_file, AOCCalculator, AOCFileOrParseError, Answer,
+};
 use parser::{OrderRule, PageNumber};
 use std::collections::{HashMap, HashSet};
 
@@ -19,9 +21,12 @@ impl AOCCalculator for Day5 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -64,38 +69,17 @@ impl Day5 {
             .sum::<usize>()
     }
 
-    fn insert_element(
-        &self,
-        mut acc: Vec<usize>,
-        x: usize,
-        invalidator: &HashMap<&usize, HashSet<usize>>,
-    ) -> Vec<usize> {
-        // Find the position of the first invalid element that we have to insert before
-        // (or default to inserting at the end)
-        let position = acc
-            .iter()
-            .position(|p| invalidator.get(&x).unwrap_or(&HashSet::new()).contains(p))
-            .unwrap_or(acc.len());
-
-        // is there a better way to make this functional?
-        // (I guess since it moves acc into context, we don't have to worry that this is
-        // technically "in place" editing)
-        acc.insert(position, x);
-        acc
-    }
-
-    fn reorder_page_number(
-        &self,
-        page_number: &[usize],
-        invalidator: &HashMap<&usize, HashSet<usize>>,
-    ) -> Vec<usize> {
-        page_number
+    fn reorder_page_number(&self, page_number: &[usize]) -> Vec<usize> {
+        // The rules loop when taken all together, but never among the pages of one update
+        let rules = self
+            .rules
             .iter()
-            // Insert each character 1 at a time, at the latest possible place that doesn't break
-            // the rules invalidator.
-            // note: this doesn't handle loops, but luckily the real data doesn't include any
-            // looped failures
-            .fold(vec![], |acc, x| self.insert_element(acc, *x, invalidator))
+            .filter(|(left, right)| page_number.contains(left) && page_number.contains(right))
+            .copied();
+        Dag::new(page_number.iter().copied(), rules)
+            .expect("The rules between an update's pages shouldn't loop")
+            .topological_order()
+            .to_vec()
     }
 
     fn calculate_day_b(&self) -> usize {
@@ -103,7 +87,7 @@ impl Day5 {
         self.page_numbers
             .iter()
             .filter(|page_number| !self.is_page_number_correct(page_number, &invalidator))
-            .map(|page_number| self.reorder_page_number(page_number, &invalidator))
+            .map(|page_number| self.reorder_page_number(page_number))
             .map(|page_number| self.get_middle_value(&page_number))
             .sum::<usize>()
     }
@@ -20,11 +20,7 @@ peg::parser! { pub grammar day5_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<(Vec<OrderRule>, Vec<PageNumber>), AOCFileOrParseError> {
-    if let Ok(ret) = day5_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day5_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
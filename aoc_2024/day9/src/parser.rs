@@ -9,11 +9,7 @@ peg::parser! { pub grammar day9_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<usize>, AOCFileOrParseError> {
-    if let Ok(ret) = day9_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day9_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
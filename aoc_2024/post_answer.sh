@@ -1,15 +0,0 @@
-#!/bin/bash
-day=$1
-level=$2
-answer=$3
-curl "https://adventofcode.com/2024/day/${day}/answer" \
-  -H 'accept: text/html,application/xhtml+xml,application/xml;q=0.9,image/avif,image/webp,image/apng,*/*;q=0.8,application/signed-exchange;v=b3;q=0.7' \
-  -H 'accept-language: en-US,en;q=0.9' \
-  -H 'cache-control: max-age=0' \
-  -H 'content-type: application/x-www-form-urlencoded' \
-  -H "cookie: session=${SESSION};" \
-  -H 'sec-fetch-dest: document' \
-  -H 'sec-fetch-mode: navigate' \
-  -H 'sec-fetch-site: same-origin' \
-  -H 'sec-fetch-user: ?1' \
-  --data-raw "level=${level}&answer=${answer}"
@@ -0,0 +1,79 @@
+pub use aoc_helpers::{AOCCalculator, AOCFileOrParseError, Answer};
+use day1::Day01;
+use day10::Day10;
+use day11::Day11;
+use day12::Day12;
+use day13::Day13;
+use day14::Day14;
+use day15::Day15;
+use day16::Day16;
+use day17::Day17;
+use day18::Day18;
+use day19::Day19;
+use day2::Day2;
+use day20::Day20;
+use day21::Day21;
+use day22::Day22;
+use day23::Day23;
+use day24::Day24;
+use day25::Day25;
+use day3::Day3;
+use day4::Day4;
+use day5::Day5;
+use day6::Day6;
+use day7::Day7;
+use day8::Day8;
+use day9::Day9;
+
+pub const YEAR: usize = 2024;
+
+pub type DayConstructor = fn(&str) -> Result<Box<dyn AOCCalculator>, AOCFileOrParseError>;
+
+/// Every day solved this year, in order, as (day, constructor) pairs for the `aoc` runner
+pub const DAYS: [(usize, DayConstructor); 25] = [
+    (1, boxed::<Day01>),
+    (2, boxed::<Day2>),
+    (3, boxed::<Day3>),
+    (4, boxed::<Day4>),
+    (5, boxed::<Day5>),
+    (6, boxed::<Day6>),
+    (7, boxed::<Day7>),
+    (8, boxed::<Day8>),
+    (9, boxed::<Day9>),
+    (10, boxed::<Day10>),
+    (11, boxed::<Day11>),
+    (12, boxed::<Day12>),
+    (13, boxed::<Day13>),
+    (14, boxed::<Day14>),
+    (15, boxed::<Day15>),
+    (16, boxed::<Day16>),
+    (17, boxed::<Day17>),
+    (18, boxed::<Day18>),
+    (19, boxed::<Day19>),
+    (20, boxed::<Day20>),
+    (21, boxed::<Day21>),
+    (22, boxed::<Day22>),
+    (23, boxed::<Day23>),
+    (24, boxed::<Day24>),
+    (25, boxed::<Day25>),
+];
+
+fn boxed<T: AOCCalculator + 'static>(
+    input_file: &str,
+) -> Result<Box<dyn AOCCalculator>, AOCFileOrParseError> {
+    Ok(Box::new(T::new(input_file)?))
+}
+
+/// Where a day's puzzle input lives, regardless of the directory the runner is started from
+pub fn input_file(day: usize) -> String {
+    format!(
+        "{}/day{}/data/input_data.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        day
+    )
+}
+
+/// The confirmed answers for this year, checked by `aoc --check`
+pub fn ledger_file() -> String {
+    format!("{}/answers.toml", env!("CARGO_MANIFEST_DIR"))
+}
@@ -15,11 +15,7 @@ peg::parser! { pub grammar day01_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<isize>, AOCFileOrParseError> {
-    if let Ok(ret) = day01_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day01_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
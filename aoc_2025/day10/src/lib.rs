@@ -1,8 +1,9 @@
 mod parser;
 use crate::parser::{parse_data, Machine};
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::linear_algebra::{reduced_row_echelon, Rational};
+use aoc_helpers::modular_math::least_common_multiple;
+use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 use itertools::Itertools;
-use z3::{ast::Int, Optimize};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Day10 {
@@ -16,9 +17,12 @@ impl AOCCalculator for Day10 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -33,7 +37,7 @@ impl Day10 {
     fn calculate_day_b(&self) -> usize {
         self.machines
             .iter()
-            .map(|machine| self.per_machine_solve_part_b_z3(machine))
+            .map(|machine| self.per_machine_solve_part_b(machine))
             .sum()
     }
 
@@ -112,55 +116,148 @@ impl Day10 {
     /// ---------------
     /// so unknown variables are s_3 and s_5, how do we minimise?
     ///
-    /// Typically with something like this we would use LMS to work out the best answer:
-    /// question: if we're doing the LMS do we even need the gaussian elimination first?
-    ///
-    ///  Start with s=[0;6]
-    ///  Error = [3, 5, 1, 2];
-    ///  update by something (let's say sqrt(value))
-    ///  s = [sqrt(2), sqrt(5), sqrt(1), sqrt(1) + sqrt(2), sqrt(3), sqrt(3) + sqrt(5) - sqrt(1) - sqrt(2)]
-    ///  s = [1.414, 2.236, 1.0, 2.414, 1.732, 1.554]
+    /// After reducing to row echelon form, the buttons without a pivot are the free variables.
+    /// No button can be pressed more times than the smallest counter it adds to, which bounds
+    /// each free variable, so we search every combination of free presses within those bounds.
+    /// Each combination fixes the pivot presses, which are only valid if they come out as
+    /// non-negative whole numbers, and we keep the valid combination with the fewest presses.
     ///
-    ///  gives us values: [3.286, 3.790, 1.860, 2.27]
-    ///
-    #[allow(dead_code)]
-    fn per_machine_solve_part_b(&self, _machine: &Machine) -> usize {
-        0
-    }
-
-    fn per_machine_solve_part_b_z3(&self, machine: &Machine) -> usize {
+    fn per_machine_solve_part_b(&self, machine: &Machine) -> usize {
         let (_, buttons, targets) = machine;
-        // the number of times we press each button
-        let variables: Vec<Int> = (0..buttons.len())
-            .map(|i| Int::fresh_const(&format!("s{}", i)))
+        // one row per counter, one column per button, with the target on the end
+        let matrix: Vec<Vec<i128>> = targets
+            .iter()
+            .enumerate()
+            .map(|(counter, &target)| {
+                buttons
+                    .iter()
+                    .map(|button| button.contains(&counter) as i128)
+                    .chain([target as i128])
+                    .collect()
+            })
             .collect();
-        let optimize = Optimize::new();
-        // we cannot negative press buttons
-        for variable in variables.iter() {
-            optimize.assert(&variable.ge(0));
-        }
-        // assert that button presses give our targets
-        for (i, target) in targets.iter().enumerate() {
-            optimize.assert(
-                &buttons
+        let (rows, pivots) = reduced_row_echelon(&matrix);
+        assert!(
+            !pivots.contains(&buttons.len()),
+            "The machine can't reach its targets"
+        );
+        let free_buttons: Vec<usize> = (0..buttons.len())
+            .filter(|button| !pivots.contains(button))
+            .collect();
+        let equations: Vec<Equation> = rows[..pivots.len()]
+            .iter()
+            .map(|row| Equation::new(row, &free_buttons))
+            .collect();
+        let limits: Vec<i128> = free_buttons
+            .iter()
+            .map(|&button| {
+                buttons[button]
                     .iter()
-                    .enumerate()
-                    .filter(|(_, button)| button.contains(&i))
-                    .map(|(var_index, _)| &variables[var_index])
-                    .sum::<Int>()
-                    .eq(Int::from_u64(*target as u64)),
-            )
+                    .map(|&counter| targets[counter] as i128)
+                    .min()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let mut best = None;
+        self.fewest_presses(&equations, &limits, &mut vec![], &mut best);
+        best.expect("The machine should have a solution") as usize
+    }
+
+    /// A depth first search over the free presses, skipping any branch that can't balance every
+    /// equation or can't beat the best total found so far
+    fn fewest_presses(
+        &self,
+        equations: &[Equation],
+        limits: &[i128],
+        free_presses: &mut Vec<i128>,
+        best: &mut Option<i128>,
+    ) {
+        if free_presses.len() == limits.len() {
+            let total = equations
+                .iter()
+                .map(|equation| equation.pivot_presses(free_presses))
+                .sum::<Option<i128>>()
+                .map(|presses| presses + free_presses.iter().sum::<i128>());
+            if let Some(total) = total {
+                *best = Some(best.map_or(total, |best| best.min(total)));
+            }
+            return;
+        }
+        for presses in 0..=limits[free_presses.len()] {
+            free_presses.push(presses);
+            let fewest = equations
+                .iter()
+                .map(|equation| equation.fewest_pivot_presses(free_presses, limits))
+                .sum::<Option<i128>>()
+                .map(|presses| presses + free_presses.iter().sum::<i128>());
+            if fewest.is_some_and(|fewest| best.is_none_or(|best| fewest < best)) {
+                self.fewest_presses(equations, limits, free_presses, best);
+            }
+            free_presses.pop();
+        }
+    }
+}
+
+/// One row of the reduced matrix, scaled up to whole numbers:
+/// `scale * pivot + sum(free_coefficients * free) == constant`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Equation {
+    scale: i128,
+    free_coefficients: Vec<i128>,
+    constant: i128,
+}
+
+impl Equation {
+    fn new(row: &[Rational], free_buttons: &[usize]) -> Equation {
+        let scale = row.iter().fold(1, |scale, value| {
+            least_common_multiple(scale, value.denominator() as usize)
+        });
+        let scaled = |value: Rational| {
+            (value * Rational::from(scale as i128))
+                .to_integer()
+                .expect("Scaling by the common denominator leaves whole numbers")
+        };
+        Equation {
+            scale: scale as i128,
+            free_coefficients: free_buttons
+                .iter()
+                .map(|&button| scaled(row[button]))
+                .collect(),
+            constant: scaled(row[row.len() - 1]),
         }
-        // the goal we minimise towards
-        let ret = Int::fresh_const("ret");
-        // assert that our goal is the sum of all the button presses
-        optimize.assert(&variables.iter().sum::<Int>().eq(&ret));
-        // minimise
-        optimize.minimize(&ret);
-        optimize.check(&[]);
-        // retrieve the result
-        let model = optimize.get_model().unwrap();
-        model.get_const_interp(&ret).unwrap().as_u64().unwrap() as usize
+    }
+
+    /// The fewest times the pivot button could be pressed once the rest of the free presses are
+    /// chosen, or `None` if every choice leaves it negative
+    fn fewest_pivot_presses(&self, free_presses: &[i128], limits: &[i128]) -> Option<i128> {
+        let (chosen, unchosen) = self.free_coefficients.split_at(free_presses.len());
+        let chosen_total = chosen
+            .iter()
+            .zip(free_presses)
+            .map(|(coefficient, presses)| coefficient * presses)
+            .sum::<i128>();
+        let (least_unchosen, most_unchosen) = unchosen
+            .iter()
+            .zip(&limits[free_presses.len()..])
+            .map(|(coefficient, limit)| coefficient * limit)
+            .fold((0, 0), |(least, most), extreme| {
+                (least + extreme.min(0), most + extreme.max(0))
+            });
+        let most_remaining = self.constant - chosen_total - least_unchosen;
+        let least_remaining = self.constant - chosen_total - most_unchosen;
+        (most_remaining >= 0).then(|| (least_remaining.max(0) + self.scale - 1) / self.scale)
+    }
+
+    /// How many times the pivot button gets pressed, if that's a non-negative whole number
+    fn pivot_presses(&self, free_presses: &[i128]) -> Option<i128> {
+        let remainder = self.constant
+            - self
+                .free_coefficients
+                .iter()
+                .zip(free_presses)
+                .map(|(coefficient, presses)| coefficient * presses)
+                .sum::<i128>();
+        (remainder >= 0 && remainder % self.scale == 0).then_some(remainder / self.scale)
     }
 }
 
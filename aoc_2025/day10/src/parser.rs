@@ -29,11 +29,7 @@ peg::parser! { pub grammar day10_parser() for str {
 }}
 
 pub fn parse_data(input: &str) -> Result<Vec<Machine>, AOCFileOrParseError> {
-    if let Ok(ret) = day10_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day10_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -1,69 +1,50 @@
 mod parser;
-use std::collections::HashMap;
 
 use crate::parser::parse_data;
-use aoc_helpers::{read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{dag::Dag, read_input_file, AOCCalculator, AOCFileOrParseError, Answer};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Day11 {
-    server_rack: HashMap<String, Vec<String>>,
+    server_rack: Dag<String>,
 }
 
 impl AOCCalculator for Day11 {
     fn new(filename: &str) -> Result<Day11, AOCFileOrParseError> {
-        Ok(Day11 {
-            server_rack: parse_data(&read_input_file(filename)?)?,
-        })
+        let outputs = parse_data(&read_input_file(filename)?)?;
+        let server_rack = Dag::new(
+            [],
+            outputs.into_iter().flat_map(|(device, outputs)| {
+                outputs
+                    .into_iter()
+                    .map(move |output| (device.clone(), output))
+            }),
+        )
+        .map_err(|cycle| {
+            AOCFileOrParseError::InvalidInput(format!(
+                "devices are wired in a loop: {:?}",
+                cycle.nodes
+            ))
+        })?;
+        Ok(Day11 { server_rack })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
 impl Day11 {
     fn calculate_day_a(&self) -> usize {
-        self.find_paths_from_source_to_dest("you", "out")
+        self.server_rack.count_paths("you", "out")
     }
 
     fn calculate_day_b(&self) -> usize {
-        self.find_paths_from_source_to_dest("svr", "fft")
-            * self.find_paths_from_source_to_dest("fft", "dac")
-            * self.find_paths_from_source_to_dest("dac", "out")
-            + self.find_paths_from_source_to_dest("svr", "dac")
-                * self.find_paths_from_source_to_dest("dac", "fft")
-                * self.find_paths_from_source_to_dest("fft", "out")
-    }
-
-    fn find_paths_from_source_to_dest(&self, source: &str, dest: &str) -> usize {
-        let path_count_map = self.build_path_count_to_target(&HashMap::new(), source, dest);
-        *path_count_map.get(source).unwrap()
-    }
-
-    fn build_path_count_to_target(
-        &self,
-        path_count_cache: &HashMap<String, usize>,
-        source: &str,
-        dest: &str,
-    ) -> HashMap<String, usize> {
-        // basically doing memoisation of the result (assumes no cycles)
-        let mut path_count = 0;
-        let mut ret = path_count_cache.clone();
-        if let Some(next_nodes) = self.server_rack.get(source) {
-            for next_node in next_nodes.iter() {
-                if next_node == dest {
-                    path_count += 1;
-                } else if let Some(next_node_num_paths) = ret.get(next_node) {
-                    path_count += next_node_num_paths
-                } else {
-                    ret = self.build_path_count_to_target(&ret, next_node, dest);
-                    path_count += ret.get(next_node).unwrap();
-                }
-            }
-        }
-        ret.insert(source.to_string(), path_count);
-        ret
+        self.server_rack
+            .count_paths_via("svr", "out", &["fft", "dac"])
     }
 }
 
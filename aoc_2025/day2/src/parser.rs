@@ -14,11 +14,7 @@ peg::parser! {
 }
 
 pub fn parse_data(input: &str) -> Result<Vec<Range<usize>>, AOCFileOrParseError> {
-    if let Ok(ret) = day2_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day2_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -4,7 +4,7 @@ use std::collections::HashSet;
 use crate::parser::parse_data;
 use aoc_helpers::{
     point2d::{Neighbours, Point2D},
-    read_input_file, AOCCalculator, AOCFileOrParseError,
+    read_input_file, AOCCalculator, AOCFileOrParseError, Answer,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,9 +19,12 @@ impl AOCCalculator for Day4 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -26,11 +26,7 @@ peg::parser! {
 }
 
 pub fn parse_data(input: &str) -> Result<HashSet<Point2D>, AOCFileOrParseError> {
-    if let Ok(ret) = day4_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+    day4_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
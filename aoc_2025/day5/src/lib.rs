@@ -1,28 +1,35 @@
-mod ingredient_range;
 mod parser;
-use crate::ingredient_range::{IngredientRange, RangeOverlaps};
 use crate::parser::parse_data;
-use aoc_helpers::{read_input_file, vec::Concatable, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{
+    interval::IntervalSet, read_input_file, AOCCalculator, AOCFileOrParseError, Answer,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Day5 {
     available_ingredient_ids: Vec<usize>,
-    fresh_ingredient_id_ranges: Vec<IngredientRange>,
+    fresh_ingredient_ids: IntervalSet<usize>,
 }
 
 impl AOCCalculator for Day5 {
     fn new(filename: &str) -> Result<Day5, AOCFileOrParseError> {
         let (fresh_ingredient_id_ranges, available_ingredient_ids) =
             parse_data(&read_input_file(filename)?)?;
+        let mut fresh_ingredient_ids = IntervalSet::new();
+        for range in fresh_ingredient_id_ranges {
+            fresh_ingredient_ids.insert_inclusive(range);
+        }
         Ok(Day5 {
-            fresh_ingredient_id_ranges,
+            fresh_ingredient_ids,
             available_ingredient_ids,
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -30,46 +37,12 @@ impl Day5 {
     fn calculate_day_a(&self) -> usize {
         self.available_ingredient_ids
             .iter()
-            .filter(|ingredient_id| {
-                self.fresh_ingredient_id_ranges
-                    .iter()
-                    .any(|fresh_range| fresh_range.contains(ingredient_id))
-            })
+            .filter(|ingredient_id| self.fresh_ingredient_ids.contains(ingredient_id))
             .count()
     }
 
-    fn combine_ranges(
-        &self,
-        fresh_ranges: &[IngredientRange],
-        to_combine: &IngredientRange,
-    ) -> Vec<IngredientRange> {
-        let (unchanged, ranges_to_merge): (Vec<_>, Vec<_>) = fresh_ranges
-            .iter()
-            .cloned()
-            .partition(|range| !range.overlaps(to_combine));
-        let combined_range = ranges_to_merge
-            .iter()
-            .filter(|range| range.overlaps(to_combine))
-            .fold(
-                to_combine.clone(),
-                |acc: IngredientRange, x: &IngredientRange| {
-                    acc.overlap(x)
-                        .expect("Already filtered on overlapped values")
-                },
-            );
-        unchanged.concat_element(combined_range)
-    }
-
     fn calculate_day_b(&self) -> usize {
-        self.fresh_ingredient_id_ranges
-            .iter()
-            .fold(
-                self.fresh_ingredient_id_ranges.clone(),
-                |combined_ranges, to_combine| self.combine_ranges(&combined_ranges, to_combine),
-            )
-            .into_iter()
-            .map(|ingredient_range| ingredient_range.len())
-            .sum()
+        self.fresh_ingredient_ids.len()
     }
 }
 
@@ -1,26 +1,24 @@
 extern crate peg;
-use crate::ingredient_range::IngredientRange;
 use aoc_helpers::AOCFileOrParseError;
+use std::ops::RangeInclusive;
 
 peg::parser! { pub grammar day5_parser() for str {
     rule number() -> usize
         = n:$(['0'..='9']+) { n.parse().unwrap_or_else(|_| panic!("Was expecting a number string {}", n))}
-    rule range() -> IngredientRange
+    rule range() -> RangeInclusive<usize>
         = start:number() "-" end:number() { start..=end }
-    rule ranges() -> Vec<IngredientRange>
+    rule ranges() -> Vec<RangeInclusive<usize>>
         = ranges:range() ++ ("\n" +) { ranges }
     rule numbers() -> Vec<usize>
         = lines:number() ++ ("\n" +) { lines }
-    pub rule parse() -> (Vec<IngredientRange>, Vec<usize>)
+    pub rule parse() -> (Vec<RangeInclusive<usize>>, Vec<usize>)
         = ranges:ranges() "\n" * numbers:numbers() "\n" * { (ranges, numbers) }
 }}
 
-pub fn parse_data(input: &str) -> Result<(Vec<IngredientRange>, Vec<usize>), AOCFileOrParseError> {
-    if let Ok(ret) = day5_parser::parse(input) {
-        Ok(ret)
-    } else {
-        Err(AOCFileOrParseError)
-    }
+pub fn parse_data(
+    input: &str,
+) -> Result<(Vec<RangeInclusive<usize>>, Vec<usize>), AOCFileOrParseError> {
+    day5_parser::parse(input).map_err(|e| AOCFileOrParseError::from_peg(input, e))
 }
 
 #[cfg(test)]
@@ -34,7 +32,7 @@ mod test {
         let input_str = read_input_file("data/test_data.txt").unwrap();
         let (actual_ranges, actual_numbers) =
             day5_parser::parse(&input_str).expect("Should parse successfully");
-        let expected_ranges: Vec<IngredientRange> = vec![3..=5, 10..=14, 16..=20, 12..=18];
+        let expected_ranges: Vec<RangeInclusive<usize>> = vec![3..=5, 10..=14, 16..=20, 12..=18];
         let expected_numbers: Vec<usize> = vec![1, 5, 8, 11, 17, 32];
         assert_eq!(expected_ranges, actual_ranges);
         assert_eq!(expected_numbers, actual_numbers);
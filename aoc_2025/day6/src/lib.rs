@@ -4,7 +4,7 @@ use std::ops::Range;
 
 use crate::operator::Operator;
 use crate::parser::parse_data;
-use aoc_helpers::{read_input_file, vec::Transposable, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{read_input_file, vec::Transposable, AOCCalculator, AOCFileOrParseError, Answer};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Day6 {
@@ -18,9 +18,12 @@ impl AOCCalculator for Day6 {
         Ok(Day6 { numbers, operators })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
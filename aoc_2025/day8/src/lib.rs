@@ -1,8 +1,10 @@
 mod parser;
-use std::collections::HashSet;
 
 use crate::parser::parse_data;
-use aoc_helpers::{point3d::Point3D, read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{
+    point3d::Point3D, read_input_file, union_find::UnionFind, AOCCalculator, AOCFileOrParseError,
+    Answer,
+};
 use itertools::Itertools;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -17,39 +19,32 @@ impl AOCCalculator for Day8 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a(1000));
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a(1000).into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
 impl Day8 {
     fn calculate_day_a(&self, iterations: usize) -> usize {
-        let circuits = self
-            .get_sorted_distances()
-            .into_iter()
-            .take(iterations)
-            .fold(self.get_initial_circuits(), |circuits, next| {
-                self.add_next_connection_to_circuits(circuits, &next)
-            });
+        let mut circuits = self.get_initial_circuits();
+        for (_, a, b) in self.get_sorted_distances().into_iter().take(iterations) {
+            circuits.union(&a, &b);
+        }
         self.top_3_circuits(&circuits)
     }
 
     fn calculate_day_b(&self) -> usize {
-        let last_connection_err = self.get_sorted_distances().into_iter().try_fold(
-            self.get_initial_circuits(),
-            |circuits, next| {
-                if self.is_connection_noop(&circuits, &next) {
-                    Ok(circuits)
-                } else if circuits.len() > 2 {
-                    Ok(self.add_next_connection_to_circuits(circuits, &next))
-                } else {
-                    Err(next)
-                }
-            },
-        );
-        let last_connection = last_connection_err.unwrap_err();
-        (last_connection.1.x * last_connection.2.x) as usize
+        let mut circuits = self.get_initial_circuits();
+        let (_, a, b) = self
+            .get_sorted_distances()
+            .into_iter()
+            .find(|(_, a, b)| circuits.union(a, b) && circuits.component_count() == 1)
+            .expect("Every junction box should end up in one circuit");
+        (a.x * b.x) as usize
     }
 
     fn get_sorted_distances(&self) -> Vec<(f64, Point3D, Point3D)> {
@@ -66,48 +61,12 @@ impl Day8 {
         distances.into_iter().step_by(2).collect()
     }
 
-    fn get_initial_circuits(&self) -> Vec<HashSet<Point3D>> {
-        self.locations
-            .iter()
-            .cloned()
-            .map(|location| HashSet::from([location]))
-            .collect()
-    }
-
-    fn add_next_connection_to_circuits(
-        &self,
-        circuits: Vec<HashSet<Point3D>>,
-        next_connection: &(f64, Point3D, Point3D),
-    ) -> Vec<HashSet<Point3D>> {
-        if self.is_connection_noop(&circuits, next_connection) {
-            return circuits;
-        }
-        let (to_change, remaining): (Vec<_>, Vec<_>) = circuits
-            .iter()
-            .partition(|circuit| {
-                circuit.contains(&next_connection.1) || circuit.contains(&next_connection.2)
-            })
-            .clone();
-
-        let mut first = to_change[0].clone();
-        to_change[1].iter().for_each(|p| {
-            first.insert(*p);
-        });
-        [vec![first], remaining.into_iter().cloned().collect()].concat()
-    }
-
-    fn is_connection_noop(
-        &self,
-        circuits: &[HashSet<Point3D>],
-        next: &(f64, Point3D, Point3D),
-    ) -> bool {
-        circuits
-            .iter()
-            .any(|circuit| circuit.contains(&next.1) && circuit.contains(&next.2))
+    fn get_initial_circuits(&self) -> UnionFind<Point3D> {
+        self.locations.iter().cloned().collect()
     }
 
-    fn top_3_circuits(&self, circuits: &[HashSet<Point3D>]) -> usize {
-        let mut by_len: Vec<_> = circuits.iter().map(|points| points.len()).collect();
+    fn top_3_circuits(&self, circuits: &UnionFind<Point3D>) -> usize {
+        let mut by_len = circuits.component_sizes();
         by_len.sort();
         by_len
             .into_iter()
@@ -2,7 +2,10 @@ mod parser;
 use std::ops::RangeInclusive;
 
 use crate::parser::parse_data;
-use aoc_helpers::{point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError};
+use aoc_helpers::{
+    interval::IntervalSet, point2d::Point2D, read_input_file, AOCCalculator, AOCFileOrParseError,
+    Answer,
+};
 use itertools::Itertools;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -25,9 +28,12 @@ impl AOCCalculator for Day9 {
         })
     }
 
-    fn print_results(&self, name: &str) {
-        println!("{}a answer is {:?}", name, self.calculate_day_a());
-        println!("{}b answer is {:?}", name, self.calculate_day_b());
+    fn part_a(&self) -> Answer {
+        self.calculate_day_a().into()
+    }
+
+    fn part_b(&self) -> Answer {
+        self.calculate_day_b().into()
     }
 }
 
@@ -134,38 +140,11 @@ impl Day9 {
         range: RangeInclusive<isize>,
         covers: &[RangeInclusive<isize>],
     ) -> bool {
-        covers
-            .iter()
-            .fold(vec![range], |acc, x| {
-                acc.iter()
-                    .flat_map(|range| self.subtract_range(range, x))
-                    .collect()
-            })
-            .is_empty()
-    }
-
-    fn subtract_range(
-        &self,
-        range: &RangeInclusive<isize>,
-        to_subtract: &RangeInclusive<isize>,
-    ) -> Vec<RangeInclusive<isize>> {
-        if range.contains(to_subtract.start()) && range.contains(to_subtract.end()) {
-            vec![
-                *range.start()..=(to_subtract.start() - 1),
-                (to_subtract.end() + 1)..=*range.end(),
-            ]
-        } else if range.contains(to_subtract.start()) {
-            vec![*range.start()..=(to_subtract.start() - 1)]
-        } else if range.contains(to_subtract.end()) {
-            vec![(to_subtract.end() + 1)..=*range.end()]
-        } else if to_subtract.contains(range.start()) && to_subtract.contains(range.end()) {
-            vec![]
-        } else {
-            vec![range.clone()]
+        let mut covered = IntervalSet::new();
+        for cover in covers {
+            covered.insert_inclusive(cover.clone());
         }
-        .into_iter()
-        .filter(|r| r.end() - r.start() >= 0)
-        .collect()
+        covered.contains_range(&(*range.start()..*range.end() + 1))
     }
 }
 
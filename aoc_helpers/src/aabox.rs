@@ -0,0 +1,225 @@
+use std::ops::Range;
+
+/// An axis-aligned box of integer cells in `N` dimensions, with `min` included and `max`
+/// excluded on every axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AABox<const N: usize> {
+    pub min: [isize; N],
+    pub max: [isize; N],
+}
+
+impl<const N: usize> AABox<N> {
+    pub fn new(min: [isize; N], max: [isize; N]) -> AABox<N> {
+        AABox { min, max }
+    }
+
+    /// For inputs that give the last cell on each axis, like `x=10..12`
+    pub fn from_inclusive(min: [isize; N], max: [isize; N]) -> AABox<N> {
+        AABox {
+            min,
+            max: max.map(|max| max + 1),
+        }
+    }
+
+    pub fn from_ranges(ranges: [Range<isize>; N]) -> AABox<N> {
+        AABox {
+            min: std::array::from_fn(|axis| ranges[axis].start),
+            max: std::array::from_fn(|axis| ranges[axis].end),
+        }
+    }
+
+    pub fn range(&self, axis: usize) -> Range<isize> {
+        self.min[axis]..self.max[axis]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|axis| self.min[axis] >= self.max[axis])
+    }
+
+    /// How many cells are in the box
+    pub fn volume(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        (0..N)
+            .map(|axis| (self.max[axis] - self.min[axis]) as usize)
+            .product()
+    }
+
+    pub fn contains(&self, point: &[isize; N]) -> bool {
+        (0..N).all(|axis| self.range(axis).contains(&point[axis]))
+    }
+
+    /// Whether every cell of `other` is also in this box
+    pub fn contains_box(&self, other: &AABox<N>) -> bool {
+        other.is_empty()
+            || (0..N)
+                .all(|axis| self.min[axis] <= other.min[axis] && other.max[axis] <= self.max[axis])
+    }
+
+    /// The cells in both boxes, if there are any
+    pub fn intersection(&self, other: &AABox<N>) -> Option<AABox<N>> {
+        let intersection = AABox {
+            min: std::array::from_fn(|axis| self.min[axis].max(other.min[axis])),
+            max: std::array::from_fn(|axis| self.max[axis].min(other.max[axis])),
+        };
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    pub fn overlaps(&self, other: &AABox<N>) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Cuts the box into the part below `at` on `axis` and the part from `at` up. Either is
+    /// `None` if it would be empty.
+    pub fn split(&self, axis: usize, at: isize) -> (Option<AABox<N>>, Option<AABox<N>>) {
+        let mut below = *self;
+        below.max[axis] = below.max[axis].min(at);
+        let mut above = *self;
+        above.min[axis] = above.min[axis].max(at);
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+
+    /// The cells of this box that aren't in `other`, as at most `2 * N` boxes that don't
+    /// overlap each other
+    pub fn subtract(&self, other: &AABox<N>) -> Vec<AABox<N>> {
+        let Some(overlap) = self.intersection(other) else {
+            return if self.is_empty() { vec![] } else { vec![*self] };
+        };
+        // Slice off whatever is either side of the overlap one axis at a time, leaving the
+        // overlap itself
+        let mut pieces = vec![];
+        let mut remaining = *self;
+        for axis in 0..N {
+            let (below, rest) = remaining.split(axis, overlap.min[axis]);
+            let (middle, above) = rest
+                .expect("the overlap is inside what's left")
+                .split(axis, overlap.max[axis]);
+            pieces.extend(below);
+            pieces.extend(above);
+            remaining = middle.expect("the overlap is inside what's left");
+        }
+        pieces
+    }
+}
+
+/// The cells covered by a sequence of boxes being switched on and off, kept as boxes that don't
+/// overlap so the volume is just their sum
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BoxSet<const N: usize> {
+    boxes: Vec<AABox<N>>,
+}
+
+impl<const N: usize> BoxSet<N> {
+    pub fn new() -> BoxSet<N> {
+        BoxSet { boxes: vec![] }
+    }
+
+    pub fn boxes(&self) -> &[AABox<N>] {
+        &self.boxes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+    }
+
+    pub fn volume(&self) -> usize {
+        self.boxes.iter().map(AABox::volume).sum()
+    }
+
+    pub fn contains(&self, point: &[isize; N]) -> bool {
+        self.boxes.iter().any(|aabox| aabox.contains(point))
+    }
+
+    /// Switches on every cell in the box
+    pub fn insert(&mut self, aabox: AABox<N>) {
+        self.remove(&aabox);
+        if !aabox.is_empty() {
+            self.boxes.push(aabox);
+        }
+    }
+
+    /// Switches off every cell in the box
+    pub fn remove(&mut self, aabox: &AABox<N>) {
+        self.boxes = self
+            .boxes
+            .iter()
+            .flat_map(|existing| existing.subtract(aabox))
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_volume_and_contains() {
+        let aabox = AABox::from_inclusive([10, 10, 10], [12, 12, 12]);
+        assert_eq!(27, aabox.volume());
+        assert!(aabox.contains(&[12, 10, 11]));
+        assert!(!aabox.contains(&[13, 10, 11]));
+        assert_eq!(0, AABox::new([0, 0], [3, 0]).volume());
+        assert!(AABox::new([0, 0], [3, 0]).is_empty());
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = AABox::new([0, 0], [4, 4]);
+        let b = AABox::new([2, 3], [6, 8]);
+        assert_eq!(Some(AABox::new([2, 3], [4, 4])), a.intersection(&b));
+        assert!(a.overlaps(&b));
+        // Boxes that only touch don't share any cells
+        assert!(!a.overlaps(&AABox::new([4, 0], [6, 4])));
+        assert!(a.contains_box(&AABox::new([1, 1], [3, 4])));
+        assert!(!a.contains_box(&b));
+    }
+
+    #[test]
+    fn test_split() {
+        let aabox = AABox::from_ranges([1..4001, 1..4001, 1..4001, 1..4001]);
+        let (below, above) = aabox.split(2, 2006);
+        assert_eq!(Some(1..2006), below.map(|below| below.range(2)));
+        assert_eq!(Some(2006..4001), above.map(|above| above.range(2)));
+        assert_eq!((Some(aabox), None), aabox.split(0, 5000));
+        assert_eq!((None, Some(aabox)), aabox.split(0, 1));
+    }
+
+    #[test]
+    fn test_subtract() {
+        let outer = AABox::new([0, 0, 0], [3, 3, 3]);
+        let middle = AABox::new([1, 1, 1], [2, 2, 2]);
+        let pieces = outer.subtract(&middle);
+        assert_eq!(6, pieces.len());
+        assert_eq!(26, pieces.iter().map(AABox::volume).sum::<usize>());
+        assert!(pieces.iter().all(|piece| !piece.overlaps(&middle)));
+        for (i, a) in pieces.iter().enumerate() {
+            assert!(pieces[i + 1..].iter().all(|b| !a.overlaps(b)));
+        }
+        assert_eq!(
+            vec![middle],
+            middle.subtract(&AABox::new([5, 5, 5], [6, 6, 6]))
+        );
+        assert_eq!(Vec::<AABox<3>>::new(), middle.subtract(&outer));
+    }
+
+    #[test]
+    fn test_box_set() {
+        // The small example from 2021 day 22
+        let mut cubes = BoxSet::new();
+        cubes.insert(AABox::from_inclusive([10, 10, 10], [12, 12, 12]));
+        assert_eq!(27, cubes.volume());
+        cubes.insert(AABox::from_inclusive([11, 11, 11], [13, 13, 13]));
+        assert_eq!(27 + 19, cubes.volume());
+        cubes.remove(&AABox::from_inclusive([9, 9, 9], [11, 11, 11]));
+        assert_eq!(27 + 19 - 8, cubes.volume());
+        cubes.insert(AABox::from_inclusive([10, 10, 10], [10, 10, 10]));
+        assert_eq!(39, cubes.volume());
+        assert!(cubes.contains(&[10, 10, 10]));
+        assert!(!cubes.contains(&[11, 10, 10]));
+    }
+}
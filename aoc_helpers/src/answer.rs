@@ -0,0 +1,133 @@
+use std::fmt::Display;
+
+/// The answer to one part of a day, so it can be compared, stored or submitted
+/// rather than only printed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    String(String),
+    /// Multi-line output that has to be read by eye, one entry per row
+    AsciiArt(Vec<String>),
+    /// For parts that don't exist, such as the second half of day 25
+    None,
+}
+
+impl Answer {
+    /// Splits a multi-line rendering (e.g. something's `Display` output) into ascii art
+    pub fn ascii_art(rendered: &str) -> Answer {
+        Answer::AsciiArt(rendered.lines().map(|line| line.to_string()).collect())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::String(value) => write!(f, "{}", value),
+            Answer::AsciiArt(rows) => write!(f, "{}", rows.join("\n")),
+            Answer::None => write!(f, "-"),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Integer(value as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Integer(value as i128)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Integer(value as i128)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Integer(value as i128)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Integer(value as i128)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Integer(value as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::String(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::String(value.to_string())
+    }
+}
+
+/// How an answer is shown to the user, e.g. "day1a answer is 24000"
+pub fn format_answer(label: &str, answer: &Answer) -> String {
+    match answer {
+        Answer::None => format!("There is no {}", label),
+        Answer::AsciiArt(_) => format!("{} answer is:\n{}", label, answer),
+        _ => format!("{} answer is {}", label, answer),
+    }
+}
+
+pub fn print_answer(label: &str, answer: &Answer) {
+    println!("{}", format_answer(label, answer));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_numbers() {
+        assert_eq!(Answer::Integer(24000), 24000_usize.into());
+        assert_eq!(Answer::Integer(-3), (-3_isize).into());
+        assert_eq!(Answer::Integer(u64::MAX as i128), u64::MAX.into());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("24000", Answer::from(24000_usize).to_string());
+        assert_eq!("CMZ", Answer::from("CMZ").to_string());
+        assert_eq!("-", Answer::None.to_string());
+    }
+
+    #[test]
+    fn test_format_answer() {
+        assert_eq!(
+            "day1a answer is 24000",
+            format_answer("day1a", &24000_usize.into())
+        );
+        assert_eq!(
+            "day10b answer is:\n#.\n.#",
+            format_answer("day10b", &Answer::ascii_art("#.\n.#\n"))
+        );
+        assert_eq!("There is no day25b", format_answer("day25b", &Answer::None));
+    }
+
+    #[test]
+    fn test_ascii_art() {
+        let actual = Answer::ascii_art("##..\n#..#\n");
+        let expected = Answer::AsciiArt(vec!["##..".to_string(), "#..#".to_string()]);
+        assert_eq!(expected, actual);
+        assert_eq!("##..\n#..#", actual.to_string());
+    }
+}
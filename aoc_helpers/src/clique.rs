@@ -0,0 +1,213 @@
+//! Cliques in undirected graphs: groups of nodes that are all connected to each other.
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+#[derive(Debug, Clone)]
+pub struct UndirectedGraph<N> {
+    nodes: Vec<N>,
+    neighbours: Vec<HashSet<usize>>,
+}
+
+impl<N: Eq + Hash + Clone> UndirectedGraph<N> {
+    /// Edges can be given either way round, and more than once
+    pub fn new(edges: impl IntoIterator<Item = (N, N)>) -> UndirectedGraph<N> {
+        let mut graph = UndirectedGraph {
+            nodes: vec![],
+            neighbours: vec![],
+        };
+        let mut indices = HashMap::new();
+        for (a, b) in edges {
+            let [a, b] = [a, b].map(|node| {
+                *indices.entry(node.clone()).or_insert_with(|| {
+                    graph.nodes.push(node);
+                    graph.neighbours.push(HashSet::new());
+                    graph.nodes.len() - 1
+                })
+            });
+            if a != b {
+                graph.neighbours[a].insert(b);
+                graph.neighbours[b].insert(a);
+            }
+        }
+        graph
+    }
+
+    fn to_nodes(&self, clique: &[usize]) -> Vec<N> {
+        clique
+            .iter()
+            .map(|&index| self.nodes[index].clone())
+            .collect()
+    }
+
+    /// Every clique that can't be made any bigger, in no particular order
+    pub fn maximal_cliques(&self) -> Vec<Vec<N>> {
+        let mut cliques = vec![];
+        self.bron_kerbosch(
+            &mut vec![],
+            (0..self.nodes.len()).collect(),
+            vec![],
+            &mut |clique| cliques.push(self.to_nodes(clique)),
+        );
+        cliques
+    }
+
+    /// Bron-Kerbosch with pivoting: grows `clique` by each of the `candidates` in turn, where
+    /// the `excluded` nodes could also be added but have already had their turn. Any maximal
+    /// clique has to include the pivot or something not connected to it, so only those need a
+    /// turn.
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<usize>,
+        mut candidates: Vec<usize>,
+        mut excluded: Vec<usize>,
+        report: &mut impl FnMut(&[usize]),
+    ) {
+        let Some(pivot) = candidates
+            .iter()
+            .chain(excluded.iter())
+            .max_by_key(|&&node| {
+                candidates
+                    .iter()
+                    .filter(|candidate| self.neighbours[node].contains(candidate))
+                    .count()
+            })
+            .copied()
+        else {
+            report(clique);
+            return;
+        };
+        let turns: Vec<usize> = candidates
+            .iter()
+            .filter(|node| !self.neighbours[pivot].contains(node))
+            .copied()
+            .collect();
+        for node in turns {
+            let connected = |others: &[usize]| -> Vec<usize> {
+                others
+                    .iter()
+                    .filter(|other| self.neighbours[node].contains(other))
+                    .copied()
+                    .collect()
+            };
+            clique.push(node);
+            self.bron_kerbosch(clique, connected(&candidates), connected(&excluded), report);
+            clique.pop();
+            candidates.retain(|&candidate| candidate != node);
+            excluded.push(node);
+        }
+    }
+
+    /// The biggest clique there is (or one of them, if there's a tie)
+    pub fn maximum_clique(&self) -> Vec<N> {
+        let mut biggest = vec![];
+        self.bron_kerbosch(
+            &mut vec![],
+            (0..self.nodes.len()).collect(),
+            vec![],
+            &mut |clique| {
+                if clique.len() > biggest.len() {
+                    biggest = clique.to_vec();
+                }
+            },
+        );
+        self.to_nodes(&biggest)
+    }
+
+    /// Every clique of exactly `size` nodes with at least one node that matches
+    pub fn cliques_of_size(&self, size: usize, mut matches: impl FnMut(&N) -> bool) -> Vec<Vec<N>> {
+        let mut cliques = vec![];
+        self.extend_to_size(
+            &mut vec![],
+            (0..self.nodes.len()).collect(),
+            size,
+            &mut |clique| {
+                if clique.iter().any(|&index| matches(&self.nodes[index])) {
+                    cliques.push(self.to_nodes(clique));
+                }
+            },
+        );
+        cliques
+    }
+
+    /// Adds `candidates` to `clique` until it's `size` nodes. Only candidates after the last one
+    /// added are tried next, so each clique is only found once.
+    fn extend_to_size(
+        &self,
+        clique: &mut Vec<usize>,
+        candidates: Vec<usize>,
+        size: usize,
+        report: &mut impl FnMut(&[usize]),
+    ) {
+        if clique.len() == size {
+            report(clique);
+            return;
+        }
+        for (position, &node) in candidates.iter().enumerate() {
+            let next_candidates = candidates[position + 1..]
+                .iter()
+                .filter(|other| self.neighbours[node].contains(other))
+                .copied()
+                .collect();
+            clique.push(node);
+            self.extend_to_size(clique, next_candidates, size, report);
+            clique.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// a, b, c and d all connected, then d - e - f - d in a triangle and f - g on the end
+    fn graph() -> UndirectedGraph<char> {
+        UndirectedGraph::new([
+            ('a', 'b'),
+            ('a', 'c'),
+            ('a', 'd'),
+            ('b', 'c'),
+            ('d', 'b'),
+            ('c', 'd'),
+            ('d', 'e'),
+            ('e', 'f'),
+            ('f', 'd'),
+            ('f', 'g'),
+            ('g', 'f'),
+        ])
+    }
+
+    fn sorted(mut cliques: Vec<Vec<char>>) -> Vec<String> {
+        let mut cliques: Vec<String> = cliques
+            .iter_mut()
+            .map(|clique| {
+                clique.sort();
+                clique.iter().collect()
+            })
+            .collect();
+        cliques.sort();
+        cliques
+    }
+
+    #[test]
+    fn test_maximal_cliques() {
+        let graph = graph();
+        assert_eq!(vec!["abcd", "def", "fg"], sorted(graph.maximal_cliques()));
+        assert_eq!(vec!["abcd"], sorted(vec![graph.maximum_clique()]));
+    }
+
+    #[test]
+    fn test_cliques_of_size() {
+        let graph = graph();
+        assert_eq!(
+            vec!["abc", "abd", "acd", "bcd", "def"],
+            sorted(graph.cliques_of_size(3, |_| true))
+        );
+        assert_eq!(
+            vec!["def"],
+            sorted(graph.cliques_of_size(3, |&node| node > 'd'))
+        );
+        assert_eq!(4, graph.cliques_of_size(4, |_| true)[0].len());
+        assert!(graph.cliques_of_size(5, |_| true).is_empty());
+    }
+}
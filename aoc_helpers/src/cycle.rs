@@ -0,0 +1,167 @@
+//! Skipping ahead in simulations that are asked for the state after far more steps than can be
+//! run, by finding where they start repeating.
+//!
+//! States are compared by a fingerprint rather than directly, so a state can carry things that
+//! keep growing (like a tower's height) as long as the fingerprint only holds what decides the
+//! steps after it. Two states with the same fingerprint must go on to do the same thing.
+
+/// A simulation that runs `start` steps, and from then on repeats every `length` steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step whose state matches the one after `n` steps
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+fn run<S>(state: &mut S, step: &mut impl FnMut(&mut S), steps: usize) {
+    for _ in 0..steps {
+        step(state);
+    }
+}
+
+/// Brent's algorithm. `step` advances a state by one step in place, and must eventually
+/// repeat a fingerprint, or this never returns.
+pub fn find_cycle<S, F>(
+    initial: &S,
+    mut step: impl FnMut(&mut S),
+    mut fingerprint: impl FnMut(&S) -> F,
+) -> Cycle
+where
+    S: Clone,
+    F: Eq,
+{
+    // Find the length: the hare runs ahead in doubling stretches, with the tortoise teleported
+    // to where it is at the end of each one, until the hare comes back round to the tortoise
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = fingerprint(initial);
+    let mut hare = initial.clone();
+    step(&mut hare);
+    let mut hare_fingerprint = fingerprint(&hare);
+    while tortoise != hare_fingerprint {
+        if power == length {
+            tortoise = hare_fingerprint;
+            power *= 2;
+            length = 0;
+        }
+        step(&mut hare);
+        hare_fingerprint = fingerprint(&hare);
+        length += 1;
+    }
+
+    // Find the start: with the hare a whole cycle ahead, they first meet where the cycle starts
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    run(&mut hare, &mut step, length);
+    let mut start = 0;
+    while fingerprint(&tortoise) != fingerprint(&hare) {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// The state after `n` steps, running no more than the cycle's start and length to get there.
+/// Only meaningful when the state is entirely decided by its fingerprint.
+pub fn state_after<S, F>(
+    initial: &S,
+    mut step: impl FnMut(&mut S),
+    fingerprint: impl FnMut(&S) -> F,
+    n: usize,
+) -> S
+where
+    S: Clone,
+    F: Eq,
+{
+    let cycle = find_cycle(initial, &mut step, fingerprint);
+    let mut state = initial.clone();
+    run(&mut state, &mut step, cycle.equivalent_step(n));
+    state
+}
+
+/// A value of the state after `n` steps, where the value goes up by the same amount every time
+/// round the cycle (e.g. the height of a tower that gets the same rocks added each cycle). A
+/// value that doesn't grow just goes up by 0.
+pub fn extrapolate<S, F>(
+    initial: &S,
+    mut step: impl FnMut(&mut S),
+    fingerprint: impl FnMut(&S) -> F,
+    mut metric: impl FnMut(&S) -> i64,
+    n: usize,
+) -> i64
+where
+    S: Clone,
+    F: Eq,
+{
+    let cycle = find_cycle(initial, &mut step, fingerprint);
+    let equivalent = cycle.equivalent_step(n);
+    let mut state = initial.clone();
+    run(&mut state, &mut step, equivalent);
+    let value = metric(&state);
+    if equivalent == n {
+        return value;
+    }
+    run(&mut state, &mut step, cycle.length);
+    let growth = metric(&state) - value;
+    value + ((n - equivalent) / cycle.length) as i64 * growth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// 0, 1, 2, 5, 26, 167, 95, 101, 2, 5, ... which repeats from step 2 every 6 steps
+    fn square_plus_one(x: &mut u32) {
+        *x = (*x * *x + 1) % 255;
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let cycle = find_cycle(&0, square_plus_one, |&x| x);
+        assert_eq!(
+            Cycle {
+                start: 2,
+                length: 6
+            },
+            cycle
+        );
+        assert_eq!(1, cycle.equivalent_step(1));
+        assert_eq!(2, cycle.equivalent_step(8));
+        assert_eq!(5, cycle.equivalent_step(1_000_000_001));
+    }
+
+    #[test]
+    fn test_state_after() {
+        assert_eq!(167, state_after(&0, square_plus_one, |&x| x, 5));
+        assert_eq!(2, state_after(&0, square_plus_one, |&x| x, 8));
+        assert_eq!(167, state_after(&0, square_plus_one, |&x| x, 1_000_000_001));
+    }
+
+    #[test]
+    fn test_extrapolate_linear_growth() {
+        // Counts steps while going round 0..4 forever, so the count is its own answer
+        let step = |(position, count): &mut (u32, i64)| {
+            *position = (*position + 1) % 4;
+            *count += 1;
+        };
+        let n = 1_000_000_000_007;
+        let count = extrapolate(&(0, 0), step, |&(position, _)| position, |&(_, c)| c, n);
+        assert_eq!(n as i64, count);
+        assert_eq!(
+            3,
+            extrapolate(&(0, 0), step, |&(p, _)| p, |&(p, _)| p as i64, n)
+        );
+    }
+}
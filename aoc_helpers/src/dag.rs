@@ -0,0 +1,408 @@
+//! Directed acyclic graphs: things that depend on other things, or devices wired one way into
+//! the next.
+//!
+//! A `Dag` is only built if the edges really have no cycle (otherwise you get the cycle back),
+//! and it keeps its nodes in topological order, so path questions are a single pass along that
+//! order rather than a search.
+use std::borrow::Borrow;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+#[derive(Debug, Clone)]
+pub struct Dag<N> {
+    /// In topological order, so every edge goes from a lower index to a higher one
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    /// The edges out of each node, as the index they go to and their weight
+    edges: Vec<Vec<(usize, i64)>>,
+}
+
+// The indices are only a lookup for the nodes, so they don't need comparing (and can't be
+// without `N: Hash`)
+impl<N: PartialEq> PartialEq for Dag<N> {
+    fn eq(&self, other: &Dag<N>) -> bool {
+        self.nodes == other.nodes && self.edges == other.edges
+    }
+}
+
+impl<N: Eq> Eq for Dag<N> {}
+
+/// The edges weren't acyclic after all. Each node here has an edge to the next, and the last
+/// has one back to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FoundCycle<N> {
+    pub nodes: Vec<N>,
+}
+
+/// The best way between two nodes, by the sum of the edge weights along it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DagPath<N> {
+    pub length: i64,
+    /// From the start to the end, both included
+    pub nodes: Vec<N>,
+}
+
+impl<N: Eq + Hash + Clone> Dag<N> {
+    /// Edges go from the first node to the second. Nodes that are in edges don't have to be in
+    /// `nodes` too, which is only needed for nodes with no edges at all.
+    pub fn new(
+        nodes: impl IntoIterator<Item = N>,
+        edges: impl IntoIterator<Item = (N, N)>,
+    ) -> Result<Dag<N>, FoundCycle<N>> {
+        Dag::new_weighted(nodes, edges.into_iter().map(|(from, to)| (from, to, 1)))
+    }
+
+    pub fn new_weighted(
+        nodes: impl IntoIterator<Item = N>,
+        edges: impl IntoIterator<Item = (N, N, i64)>,
+    ) -> Result<Dag<N>, FoundCycle<N>> {
+        let mut graph = Dag {
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+            edges: Vec::new(),
+        };
+        for node in nodes {
+            graph.index_of(node);
+        }
+        for (from, to, weight) in edges {
+            let from = graph.index_of(from);
+            let to = graph.index_of(to);
+            graph.edges[from].push((to, weight));
+        }
+        let order = graph.kahn()?;
+        Ok(graph.reordered(&order))
+    }
+
+    fn index_of(&mut self, node: N) -> usize {
+        if let Some(&index) = self.indices.get(&node) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.edges.push(Vec::new());
+        index
+    }
+
+    /// Kahn's algorithm: repeatedly take a node with nothing left pointing into it. Ties go to
+    /// whichever node was seen first, so an order that's already valid comes back unchanged.
+    fn kahn(&self) -> Result<Vec<usize>, FoundCycle<N>> {
+        let mut in_degrees = vec![0; self.nodes.len()];
+        for &(to, _) in self.edges.iter().flatten() {
+            in_degrees[to] += 1;
+        }
+        let mut ready: VecDeque<usize> = (0..self.nodes.len())
+            .filter(|&index| in_degrees[index] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(index) = ready.pop_front() {
+            order.push(index);
+            for &(to, _) in self.edges[index].iter() {
+                in_degrees[to] -= 1;
+                if in_degrees[to] == 0 {
+                    ready.push_back(to);
+                }
+            }
+        }
+        if order.len() == self.nodes.len() {
+            Ok(order)
+        } else {
+            Err(self.find_cycle(&in_degrees))
+        }
+    }
+
+    /// Everything Kahn's algorithm couldn't take still has an edge into it from something else
+    /// it couldn't take, so walking backwards along those edges has to go round in a loop
+    fn find_cycle(&self, in_degrees: &[usize]) -> FoundCycle<N> {
+        let mut predecessor = vec![None; self.nodes.len()];
+        for (from, edges) in self.edges.iter().enumerate() {
+            if in_degrees[from] > 0 {
+                for &(to, _) in edges.iter() {
+                    predecessor[to] = Some(from);
+                }
+            }
+        }
+        let mut seen_at = HashMap::new();
+        let mut walk = vec![];
+        let mut current = (0..self.nodes.len())
+            .find(|&index| in_degrees[index] > 0)
+            .unwrap();
+        while !seen_at.contains_key(&current) {
+            seen_at.insert(current, walk.len());
+            walk.push(current);
+            current = predecessor[current].unwrap();
+        }
+        let mut cycle = walk.split_off(seen_at[&current]);
+        cycle.reverse();
+        FoundCycle {
+            nodes: cycle
+                .into_iter()
+                .map(|index| self.nodes[index].clone())
+                .collect(),
+        }
+    }
+
+    fn reordered(self, order: &[usize]) -> Dag<N> {
+        let mut new_index = vec![0; order.len()];
+        for (new, &old) in order.iter().enumerate() {
+            new_index[old] = new;
+        }
+        let nodes: Vec<N> = order.iter().map(|&old| self.nodes[old].clone()).collect();
+        Dag {
+            indices: nodes
+                .iter()
+                .enumerate()
+                .map(|(index, node)| (node.clone(), index))
+                .collect(),
+            edges: order
+                .iter()
+                .map(|&old| {
+                    self.edges[old]
+                        .iter()
+                        .map(|&(to, weight)| (new_index[to], weight))
+                        .collect()
+                })
+                .collect(),
+            nodes,
+        }
+    }
+
+    fn get_index<Q>(&self, node: &Q) -> Option<usize>
+    where
+        N: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.indices.get(node).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Every node, with each one after everything that has an edge into it
+    pub fn topological_order(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// The nodes `node` has edges to
+    pub fn successors<Q>(&self, node: &Q) -> impl Iterator<Item = &N>
+    where
+        N: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.get_index(node)
+            .into_iter()
+            .flat_map(|index| self.edges[index].iter())
+            .map(|&(to, _)| &self.nodes[to])
+    }
+
+    /// The number of different paths from `from` to `to` (1 if they're the same node)
+    pub fn count_paths<Q>(&self, from: &Q, to: &Q) -> usize
+    where
+        N: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        match (self.get_index(from), self.get_index(to)) {
+            (Some(from), Some(to)) => self.count_paths_between(from, to),
+            _ => 0,
+        }
+    }
+
+    fn count_paths_between(&self, from: usize, to: usize) -> usize {
+        if from > to {
+            return 0;
+        }
+        let mut ways = vec![0; to + 1];
+        ways[from] = 1;
+        for index in from..to {
+            for &(next, _) in self.edges[index].iter() {
+                if next <= to {
+                    ways[next] += ways[index];
+                }
+            }
+        }
+        ways[to]
+    }
+
+    /// The number of paths from `from` to `to` that go through every one of `via`, in whatever
+    /// order. There's only one order they can be visited in, which is the topological one.
+    pub fn count_paths_via<Q>(&self, from: &Q, to: &Q, via: &[&Q]) -> usize
+    where
+        N: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let Some(mut stops) = via
+            .iter()
+            .map(|node| self.get_index(*node))
+            .collect::<Option<Vec<usize>>>()
+        else {
+            return 0;
+        };
+        stops.sort();
+        match (self.get_index(from), self.get_index(to)) {
+            (Some(from), Some(to)) => [vec![from], stops, vec![to]]
+                .concat()
+                .windows(2)
+                .map(|leg| self.count_paths_between(leg[0], leg[1]))
+                .product(),
+            _ => 0,
+        }
+    }
+
+    /// The path from `from` to `to` with the smallest total weight, if there's any path at all
+    pub fn shortest_path<Q>(&self, from: &Q, to: &Q) -> Option<DagPath<N>>
+    where
+        N: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.best_path(self.get_index(from)?, self.get_index(to)?, |new, old| {
+            new < old
+        })
+    }
+
+    /// The path from `from` to `to` with the largest total weight, if there's any path at all
+    pub fn longest_path<Q>(&self, from: &Q, to: &Q) -> Option<DagPath<N>>
+    where
+        N: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.best_path(self.get_index(from)?, self.get_index(to)?, |new, old| {
+            new > old
+        })
+    }
+
+    fn best_path(
+        &self,
+        from: usize,
+        to: usize,
+        better: impl Fn(i64, i64) -> bool,
+    ) -> Option<DagPath<N>> {
+        if from > to {
+            return None;
+        }
+        let mut best: Vec<Option<(i64, usize)>> = vec![None; to + 1];
+        best[from] = Some((0, from));
+        for index in from..to {
+            let Some((length, _)) = best[index] else {
+                continue;
+            };
+            for &(next, weight) in self.edges[index].iter() {
+                let candidate = length + weight;
+                if next <= to && best[next].is_none_or(|(old, _)| better(candidate, old)) {
+                    best[next] = Some((candidate, index));
+                }
+            }
+        }
+        let (length, _) = best[to]?;
+        let mut nodes = vec![self.nodes[to].clone()];
+        let mut current = to;
+        while current != from {
+            current = best[current].unwrap().1;
+            nodes.push(self.nodes[current].clone());
+        }
+        nodes.reverse();
+        Some(DagPath { length, nodes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn diamonds() -> Dag<&'static str> {
+        // a -> b -> d -> e -> g
+        //  \-> c -/    \-> f -/
+        Dag::new(
+            ["lonely"],
+            [
+                ("d", "e"),
+                ("a", "b"),
+                ("a", "c"),
+                ("b", "d"),
+                ("c", "d"),
+                ("d", "f"),
+                ("e", "g"),
+                ("f", "g"),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_topological_order() {
+        let dag = diamonds();
+        assert_eq!(8, dag.len());
+        let order = dag.topological_order();
+        let position = |node| order.iter().position(|n| *n == node).unwrap();
+        for (from, to) in [("a", "b"), ("c", "d"), ("d", "e"), ("f", "g")] {
+            assert!(position(from) < position(to));
+        }
+        let mut successors: Vec<_> = dag.successors("d").collect();
+        successors.sort();
+        assert_eq!(vec![&"e", &"f"], successors);
+    }
+
+    #[test]
+    fn test_found_cycle() {
+        let cycle = Dag::new([], [(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)]).unwrap_err();
+        let start = cycle.nodes.iter().position(|&n| n == 1).unwrap();
+        let mut nodes = cycle.nodes.clone();
+        nodes.rotate_left(start);
+        assert_eq!(vec![1, 2, 3], nodes);
+        assert_eq!(
+            FoundCycle { nodes: vec![7] },
+            Dag::new([], [(7, 7)]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_count_paths() {
+        let dag = diamonds();
+        assert_eq!(4, dag.count_paths("a", "g"));
+        assert_eq!(2, dag.count_paths("d", "g"));
+        assert_eq!(1, dag.count_paths("g", "g"));
+        assert_eq!(0, dag.count_paths("g", "a"));
+        assert_eq!(0, dag.count_paths("a", "lonely"));
+        assert_eq!(0, dag.count_paths("a", "missing"));
+        assert_eq!(1, dag.count_paths_via("a", "g", &["f", "c"]));
+        assert_eq!(4, dag.count_paths_via("a", "g", &["d"]));
+        assert_eq!(1, dag.count_paths_via("a", "g", &["b", "e"]));
+        assert_eq!(0, dag.count_paths_via("a", "g", &["b", "c"]));
+    }
+
+    #[test]
+    fn test_best_paths() {
+        let dag = Dag::new_weighted(
+            [],
+            [
+                ("a", "b", 2),
+                ("b", "d", 2),
+                ("a", "c", 1),
+                ("c", "d", 5),
+                ("a", "d", 3),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            Some(DagPath {
+                length: 3,
+                nodes: vec!["a", "d"]
+            }),
+            dag.shortest_path("a", "d")
+        );
+        assert_eq!(
+            Some(DagPath {
+                length: 6,
+                nodes: vec!["a", "c", "d"]
+            }),
+            dag.longest_path("a", "d")
+        );
+        assert_eq!(None, dag.longest_path("d", "a"));
+        assert_eq!(0, dag.shortest_path("b", "b").unwrap().length);
+    }
+}
@@ -0,0 +1,181 @@
+//! Compass directions on a grid where `y` grows downwards, as it does when reading rows of
+//! input, so `Up` is `y - 1`.
+use crate::point::Coordinate;
+use crate::point2d::Point2D;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// Clockwise from up
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    pub fn turn_right(&self) -> Direction4 {
+        match self {
+            Direction4::Up => Direction4::Right,
+            Direction4::Right => Direction4::Down,
+            Direction4::Down => Direction4::Left,
+            Direction4::Left => Direction4::Up,
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction4 {
+        match self {
+            Direction4::Up => Direction4::Left,
+            Direction4::Left => Direction4::Down,
+            Direction4::Down => Direction4::Right,
+            Direction4::Right => Direction4::Up,
+        }
+    }
+
+    pub fn reverse(&self) -> Direction4 {
+        self.turn_right().turn_right()
+    }
+
+    /// Whether this is `Left` or `Right`
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, Direction4::Left | Direction4::Right)
+    }
+
+    /// The step one tile in this direction
+    pub fn as_point<T: Coordinate>(&self) -> Point2D<T> {
+        let (x, y) = match self {
+            Direction4::Up => (T::ZERO, -T::ONE),
+            Direction4::Right => (T::ONE, T::ZERO),
+            Direction4::Down => (T::ZERO, T::ONE),
+            Direction4::Left => (-T::ONE, T::ZERO),
+        };
+        Point2D { x, y }
+    }
+}
+
+/// The 4 directions of `Direction4` plus the diagonals between them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise from up
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    fn index(&self) -> usize {
+        Direction8::ALL
+            .iter()
+            .position(|direction| direction == self)
+            .unwrap()
+    }
+
+    /// An eighth of a turn clockwise
+    pub fn rotate_right(&self) -> Direction8 {
+        Direction8::ALL[(self.index() + 1) % 8]
+    }
+
+    /// An eighth of a turn anticlockwise
+    pub fn rotate_left(&self) -> Direction8 {
+        Direction8::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn turn_right(&self) -> Direction8 {
+        Direction8::ALL[(self.index() + 2) % 8]
+    }
+
+    pub fn turn_left(&self) -> Direction8 {
+        Direction8::ALL[(self.index() + 6) % 8]
+    }
+
+    pub fn reverse(&self) -> Direction8 {
+        Direction8::ALL[(self.index() + 4) % 8]
+    }
+
+    /// The step one tile in this direction, which is 1 along both axes for a diagonal
+    pub fn as_point<T: Coordinate>(&self) -> Point2D<T> {
+        match self {
+            Direction8::Up => Direction4::Up.as_point(),
+            Direction8::Right => Direction4::Right.as_point(),
+            Direction8::Down => Direction4::Down.as_point(),
+            Direction8::Left => Direction4::Left.as_point(),
+            diagonal => diagonal.rotate_left().as_point() + diagonal.rotate_right().as_point(),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Direction8 {
+        match direction {
+            Direction4::Up => Direction8::Up,
+            Direction4::Right => Direction8::Right,
+            Direction4::Down => Direction8::Down,
+            Direction4::Left => Direction8::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_direction4_turns() {
+        for direction in Direction4::ALL {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(direction.reverse(), direction.turn_left().turn_left());
+            assert_eq!(
+                direction.turn_right().as_point::<isize>(),
+                direction.as_point::<isize>().turn_right()
+            );
+        }
+        assert_eq!(Point2D { x: 0, y: -1 }, Direction4::Up.as_point::<i32>());
+        assert!(Direction4::Left.is_horizontal());
+        assert!(!Direction4::Down.is_horizontal());
+    }
+
+    #[test]
+    fn test_direction8() {
+        assert_eq!(
+            Point2D { x: 1, y: -1 },
+            Direction8::UpRight.as_point::<isize>()
+        );
+        assert_eq!(
+            Point2D { x: -1, y: 1 },
+            Direction8::DownLeft.as_point::<i64>()
+        );
+        assert_eq!(Direction8::UpLeft, Direction8::Up.rotate_left());
+        assert_eq!(Direction8::Up, Direction8::UpLeft.rotate_right());
+        assert_eq!(Direction8::DownLeft, Direction8::DownRight.turn_right());
+        assert_eq!(Direction8::UpRight, Direction8::DownLeft.reverse());
+        assert_eq!(Direction8::Left, Direction4::Left.into());
+        let total: Point2D = Direction8::ALL
+            .iter()
+            .map(|d| d.as_point())
+            .fold(Point2D::default(), |total, step| total + step);
+        assert_eq!(Point2D::default(), total);
+    }
+}
@@ -0,0 +1,286 @@
+use crate::point2d::{Neighbours, Point2D};
+use crate::AOCFileOrParseError;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of tiles, stored row by row and indexed by `Point2D` (`x` is the
+/// column, `y` the row, both from the top left)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Fails if the rows aren't all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, AOCFileOrParseError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(AOCFileOrParseError::InvalidInput(format!(
+                "row {} has {} tiles, but the first row has {}",
+                y + 1,
+                rows[y].len(),
+                width
+            )));
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Reads one tile per character, one row per line. `tile` returns `None` for characters
+    /// that aren't tiles, which is reported as a parse error at that character.
+    pub fn parse(
+        input: &str,
+        mut tile: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, AOCFileOrParseError> {
+        let mut rows = vec![];
+        for (y, line) in input.lines().enumerate() {
+            let mut row = vec![];
+            for (x, c) in line.chars().enumerate() {
+                row.push(tile(c).ok_or_else(|| AOCFileOrParseError::Parse {
+                    line: y + 1,
+                    column: x + 1,
+                    expected: "a grid tile".to_string(),
+                    input_line: line.to_string(),
+                })?);
+            }
+            rows.push(row);
+        }
+        while rows.last().is_some_and(|row| row.is_empty()) {
+            rows.pop();
+        }
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn cell_index(&self, point: &Point2D) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    fn point_at(&self, index: usize) -> Point2D {
+        Point2D::from_usize(index % self.width, index / self.width)
+    }
+
+    pub fn contains(&self, point: &Point2D) -> bool {
+        (0..self.width as isize).contains(&point.x) && (0..self.height as isize).contains(&point.y)
+    }
+
+    pub fn get(&self, point: &Point2D) -> Option<&T> {
+        self.cell_index(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: &Point2D) -> Option<&mut T> {
+        self.cell_index(point).map(|index| &mut self.cells[index])
+    }
+
+    /// Every point in the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point2D> + '_ {
+        (0..self.cells.len()).map(|index| self.point_at(index))
+    }
+
+    /// Every tile with its point, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point2D, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, tile)| (self.point_at(index), tile))
+    }
+
+    /// The up, down, left and right neighbours that are inside the grid
+    pub fn neighbours(&self, point: &Point2D) -> impl Iterator<Item = Point2D> + '_ {
+        point
+            .get_neighbours()
+            .into_iter()
+            .filter(|neighbour| self.contains(neighbour))
+    }
+
+    /// The neighbours including diagonals that are inside the grid
+    pub fn neighbours_8(&self, point: &Point2D) -> impl Iterator<Item = Point2D> + '_ {
+        point
+            .get_8_neighbours()
+            .into_iter()
+            .filter(|neighbour| self.contains(neighbour))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks of 0 isn't allowed, and an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Panics if `x` is outside the grid, like `row`
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// The first point, row by row, whose tile matches (e.g. the start of a maze)
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point2D> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.point_at(index))
+    }
+
+    pub fn find_all<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point2D> + 'a {
+        self.iter()
+            .filter(move |(_, tile)| predicate(tile))
+            .map(|(point, _)| point)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+/// Panics outside the grid, like indexing a `Vec`. Use `get` when the point might be outside.
+impl<T> Index<Point2D> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2D) -> &T {
+        self.get(&point)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point2D> for Grid<T> {
+    fn index_mut(&mut self, point: Point2D) -> &mut T {
+        self.get_mut(&point)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", point))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for tile in row {
+                write!(f, "{}", tile)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn example() -> Grid<char> {
+        Grid::parse("S.#\n.##\n..E\n", |c| "S.#E".contains(c).then_some(c)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = example();
+        assert_eq!(3, grid.width());
+        assert_eq!(3, grid.height());
+        assert_eq!(Some(&'#'), grid.get(&Point2D { x: 2, y: 0 }));
+        assert_eq!(None, grid.get(&Point2D { x: 3, y: 0 }));
+        assert_eq!(None, grid.get(&Point2D { x: 0, y: -1 }));
+        assert_eq!("S.#\n.##\n..E\n", grid.to_string());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let error = Grid::parse("S.#\n.x#\n", |c| "S.#".contains(c).then_some(c)).unwrap_err();
+        assert!(matches!(
+            error,
+            AOCFileOrParseError::Parse {
+                line: 2,
+                column: 2,
+                ..
+            }
+        ));
+        let ragged = Grid::parse("S.#\n.#\n", Some).unwrap_err();
+        assert!(matches!(ragged, AOCFileOrParseError::InvalidInput(_)));
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = example();
+        assert_eq!(Some(Point2D { x: 0, y: 0 }), grid.find(|&c| c == 'S'));
+        assert_eq!(Some(Point2D { x: 2, y: 2 }), grid.find(|&c| c == 'E'));
+        assert_eq!(None, grid.find(|&c| c == 'x'));
+        assert_eq!(3, grid.find_all(|&c| c == '#').count());
+    }
+
+    #[test]
+    fn test_neighbours_stay_inside() {
+        let grid = example();
+        let corner: Vec<Point2D> = grid.neighbours(&Point2D { x: 0, y: 0 }).collect();
+        assert_eq!(vec![Point2D { x: 0, y: 1 }, Point2D { x: 1, y: 0 }], corner);
+        assert_eq!(3, grid.neighbours_8(&Point2D { x: 0, y: 0 }).count());
+        assert_eq!(8, grid.neighbours_8(&Point2D { x: 1, y: 1 }).count());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = example();
+        assert_eq!(&['.', '#', '#'], grid.row(1));
+        assert_eq!(3, grid.rows().count());
+        let column: String = grid.column(2).collect();
+        assert_eq!("##E", column);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_column_outside_grid() {
+        example().column(3).for_each(drop);
+    }
+
+    #[test]
+    fn test_index_and_map() {
+        let mut grid = example();
+        grid[Point2D { x: 1, y: 0 }] = '#';
+        let walls = grid.map(|&c| c == '#');
+        assert_eq!(4, walls.iter().filter(|(_, &wall)| wall).count());
+        assert_eq!(
+            vec![Point2D { x: 0, y: 0 }, Point2D { x: 1, y: 0 }],
+            walls.points().take(2).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let grid = Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(Some(&3), grid.get(&Point2D { x: 0, y: 1 }));
+        let filled = Grid::filled(2, 2, 0);
+        assert_eq!(4, filled.iter().count());
+    }
+}
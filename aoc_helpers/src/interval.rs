@@ -0,0 +1,296 @@
+use std::ops::{Add, Range, RangeInclusive, Sub};
+
+/// Whether two half-open ranges share any value
+pub fn overlaps<T: Ord>(a: &Range<T>, b: &Range<T>) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+/// A set of integers, kept as sorted half-open ranges that neither overlap nor touch, so two
+/// sets with the same values always have the same ranges
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: vec![] }
+    }
+}
+
+impl<T> IntervalSet<T>
+where
+    T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet::default()
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many values are in the set
+    pub fn len(&self) -> T {
+        self.ranges.iter().fold(T::default(), |total, range| {
+            total + (range.end - range.start)
+        })
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    /// Adds the range, merging it with any it overlaps or touches
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.start >= range.end {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = match self.ranges.get(first..last) {
+            Some([head, .., tail]) => head.start.min(range.start)..tail.end.max(range.end),
+            Some([only]) => only.start.min(range.start)..only.end.max(range.end),
+            _ => range,
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// For inputs that give both ends of a range, like `3-5`
+    pub fn insert_inclusive(&mut self, range: RangeInclusive<T>)
+    where
+        T: From<u8>,
+    {
+        self.insert(*range.start()..*range.end() + T::from(1));
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let index = self.ranges.partition_point(|r| r.end <= *value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start <= *value)
+    }
+
+    /// Whether every value of `range` is in the set
+    pub fn contains_range(&self, range: &Range<T>) -> bool {
+        if range.start >= range.end {
+            return true;
+        }
+        let index = self.ranges.partition_point(|r| r.end <= range.start);
+        self.ranges
+            .get(index)
+            .is_some_and(|r| r.start <= range.start && range.end <= r.end)
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        for range in other.ranges.iter() {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = vec![];
+        for range in self.ranges.iter() {
+            for other in other.ranges.iter().filter(|other| overlaps(range, other)) {
+                ranges.push(range.start.max(other.start)..range.end.min(other.end));
+            }
+        }
+        // Already sorted and apart, since each side's ranges were
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = vec![];
+        for range in self.ranges.iter() {
+            let mut start = range.start;
+            for other in other.ranges.iter().filter(|other| overlaps(range, other)) {
+                if start < other.start {
+                    ranges.push(start..other.start);
+                }
+                start = start.max(other.end);
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+impl<T> FromIterator<Range<T>> for IntervalSet<T>
+where
+    T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>,
+{
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// Moves values in each source range by the same amount, so the start of the source lands on
+/// its destination. Values outside every source range stay where they are.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PiecewiseMap<T> {
+    pieces: Vec<(Range<T>, T)>,
+}
+
+impl<T> Default for PiecewiseMap<T> {
+    fn default() -> Self {
+        PiecewiseMap { pieces: vec![] }
+    }
+}
+
+impl<T> PiecewiseMap<T>
+where
+    T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new() -> PiecewiseMap<T> {
+        PiecewiseMap::default()
+    }
+
+    /// Source ranges are expected not to overlap. If they do, the first one inserted wins.
+    pub fn insert(&mut self, source: Range<T>, destination: T) {
+        self.pieces.push((source, destination));
+    }
+
+    pub fn map(&self, value: T) -> T {
+        self.pieces
+            .iter()
+            .find(|(source, _)| source.contains(&value))
+            .map_or(value, |(source, destination)| {
+                value - source.start + *destination
+            })
+    }
+
+    /// Every value of the set, mapped, without visiting each value
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut unmapped = set.clone();
+        let mut mapped = IntervalSet::new();
+        for (source, destination) in self.pieces.iter() {
+            let source = IntervalSet::from_iter([source.clone()]);
+            for range in unmapped.intersection(&source).ranges() {
+                let start = range.start - source.ranges[0].start + *destination;
+                mapped.insert(start..start + (range.end - range.start));
+            }
+            unmapped = unmapped.difference(&source);
+        }
+        mapped.union(&unmapped)
+    }
+}
+
+impl<T> FromIterator<(Range<T>, T)> for PiecewiseMap<T>
+where
+    T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>,
+{
+    fn from_iter<I: IntoIterator<Item = (Range<T>, T)>>(iter: I) -> Self {
+        PiecewiseMap {
+            pieces: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_overlaps() {
+        assert!(overlaps(&(0..8), &(2..4)));
+        assert!(overlaps(&(2..4), &(0..8)));
+        assert!(overlaps(&(0..6), &(4..8)));
+        assert!(overlaps(&(4..8), &(0..6)));
+        assert!(!overlaps(&(0..2), &(2..4)));
+        assert!(!overlaps(&(2..4), &(0..2)));
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_insert_normalizes() {
+        let mut set = IntervalSet::new();
+        set.insert(10..15);
+        set.insert(0..3);
+        set.insert(5..5);
+        assert_eq!(&[0..3, 10..15], set.ranges());
+        // Touching ranges merge, and one range can swallow several
+        set.insert(3..4);
+        set.insert(6..8);
+        assert_eq!(&[0..4, 6..8, 10..15], set.ranges());
+        set.insert(2..12);
+        assert_eq!(&[0..15], set.ranges());
+        assert_eq!(15, set.len());
+    }
+
+    #[test]
+    fn test_insert_inclusive() {
+        let set: IntervalSet<usize> = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().fold(
+            IntervalSet::new(),
+            |mut set, range| {
+                set.insert_inclusive(range);
+                set
+            },
+        );
+        assert_eq!(&[3..6, 10..21], set.ranges());
+        assert_eq!(14, set.len());
+        assert!(set.contains(&5));
+        assert!(!set.contains(&8));
+        assert!(set.contains(&20));
+        assert!(!set.contains(&21));
+    }
+
+    #[test]
+    fn test_contains_range() {
+        let set = IntervalSet::from_iter([0..4, 6..10]);
+        assert!(set.contains_range(&(1..4)));
+        assert!(set.contains_range(&(6..10)));
+        assert!(!set.contains_range(&(3..7)));
+        assert!(!set.contains_range(&(9..11)));
+        assert!(set.contains_range(&(5..5)));
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_set_operations() {
+        let a = IntervalSet::from_iter([0..10, 20..30]);
+        let b = IntervalSet::from_iter([5..25, 28..40]);
+        assert_eq!(&[0..40], a.union(&b).ranges());
+        assert_eq!(&[5..10, 20..25, 28..30], a.intersection(&b).ranges());
+        assert_eq!(&[0..5, 25..28], a.difference(&b).ranges());
+        assert_eq!(&[10..20, 30..40], b.difference(&a).ranges());
+        assert_eq!(IntervalSet::new(), a.difference(&a));
+    }
+
+    #[test]
+    fn test_map() {
+        let map = PiecewiseMap::from_iter([(98..100, 50), (50..98, 52)]);
+        assert_eq!(81, map.map(79));
+        assert_eq!(14, map.map(14));
+        assert_eq!(51, map.map(99));
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_map_set() {
+        let map = PiecewiseMap::from_iter([(50..60, 70)]);
+        // Below, above, within and around the one source range
+        let cases = [
+            (45..55, vec![45..50, 70..75]),
+            (55..65, vec![60..65, 75..80]),
+            (52..58, vec![72..78]),
+            (45..65, vec![45..50, 60..65, 70..80]),
+        ];
+        for (range, expected) in cases {
+            let mapped = map.map_set(&IntervalSet::from_iter([range]));
+            assert_eq!(expected, mapped.ranges());
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use peg::error::ParseError;
+use peg::str::LineCol;
+use std::fs::read_to_string;
+use std::io;
+pub mod aabox;
+pub mod answer;
+pub mod clique;
+pub mod cycle;
+pub mod dag;
+pub mod direction;
+pub mod grid;
+pub mod hash_utils;
+pub mod interval;
+pub mod linear_algebra;
+pub mod longest_path;
+pub mod memo;
+pub mod min_cut;
+pub mod modular_math;
+pub mod point;
+pub mod point2d;
+pub mod point3d;
+pub mod search;
+pub mod union_find;
+pub mod vec;
+
+pub use answer::Answer;
+
+#[derive(Debug)]
+pub enum AOCFileOrParseError {
+    /// The input file couldn't be read at all
+    File { path: String, source: io::Error },
+    /// The input didn't match the day's grammar. `line` and `column` are 1-based, as peg reports
+    /// them, and `input_line` is the text of the line that failed.
+    Parse {
+        line: usize,
+        column: usize,
+        expected: String,
+        input_line: String,
+    },
+    /// The input parsed, but isn't a puzzle the day can solve (e.g. a maze without a start)
+    InvalidInput(String),
+}
+
+impl AOCFileOrParseError {
+    pub fn from_peg(input: &str, error: ParseError<LineCol>) -> AOCFileOrParseError {
+        AOCFileOrParseError::Parse {
+            line: error.location.line,
+            column: error.location.column,
+            expected: error.expected.to_string(),
+            input_line: input
+                .lines()
+                .nth(error.location.line - 1)
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+}
+
+impl std::error::Error for AOCFileOrParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AOCFileOrParseError::File { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for AOCFileOrParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            AOCFileOrParseError::File { path, source } => {
+                write!(f, "could not read {}: {}", path, source)
+            }
+            AOCFileOrParseError::Parse {
+                line,
+                column,
+                expected,
+                input_line,
+            } => {
+                let gutter = line.to_string().len();
+                writeln!(
+                    f,
+                    "parse error at line {}, column {}: expected {}",
+                    line, column, expected
+                )?;
+                writeln!(f, "{} | {}", line, input_line)?;
+                write!(f, "{:gutter$} | {:>column$}", "", "^")
+            }
+            AOCFileOrParseError::InvalidInput(reason) => write!(f, "invalid input: {}", reason),
+        }
+    }
+}
+
+pub trait AOCCalculator {
+    fn new(input_file: &str) -> Result<Self, AOCFileOrParseError>
+    where
+        Self: std::marker::Sized;
+    fn part_a(&self) -> Answer;
+    fn part_b(&self) -> Answer;
+
+    fn print_results(&self, name: &str) {
+        answer::print_answer(&format!("{}a", name), &self.part_a());
+        answer::print_answer(&format!("{}b", name), &self.part_b());
+    }
+}
+
+pub fn read_input_file(filename: &str) -> Result<String, AOCFileOrParseError> {
+    read_to_string(filename).map_err(|source| AOCFileOrParseError::File {
+        path: filename.to_string(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    peg::parser! { grammar test_parser() for str {
+        rule number() -> usize
+            = n:$(['0'..='9']+) {? n.parse().or(Err("number")) }
+        pub rule parse() -> Vec<usize>
+            = numbers:number() ++ "\n" "\n"* { numbers }
+    }}
+
+    #[test]
+    fn test_missing_file() {
+        let error = read_input_file("data/does_not_exist.txt").unwrap_err();
+        match &error {
+            AOCFileOrParseError::File { path, source } => {
+                assert_eq!(path, "data/does_not_exist.txt");
+                assert_eq!(source.kind(), io::ErrorKind::NotFound);
+            }
+            _ => panic!("Expected a file error, got {:?}", error),
+        }
+        assert!(error
+            .to_string()
+            .starts_with("could not read data/does_not_exist.txt: "));
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let input = "12\n34\n5x6\n";
+        let error = test_parser::parse(input)
+            .map_err(|e| AOCFileOrParseError::from_peg(input, e))
+            .unwrap_err();
+        match &error {
+            AOCFileOrParseError::Parse {
+                line,
+                column,
+                input_line,
+                ..
+            } => {
+                assert_eq!((*line, *column), (3, 2));
+                assert_eq!(input_line, "5x6");
+            }
+            _ => panic!("Expected a parse error, got {:?}", error),
+        }
+        let message = error.to_string();
+        assert!(message.starts_with("parse error at line 3, column 2: expected "));
+        assert!(message.ends_with("\n3 | 5x6\n  |  ^"));
+    }
+}
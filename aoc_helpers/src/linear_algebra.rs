@@ -0,0 +1,379 @@
+//! Exact linear algebra over integer matrices, for puzzles where `f64` rounding gives the wrong
+//! answer. Matrices are given as rows. Anything that can't stay an integer is a `Rational`.
+//!
+//! Everything is `i128`, checked: a system too big for that panics instead of being silently
+//! wrong.
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A fraction in lowest terms, with a positive denominator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+/// The non-negative greatest common divisor of `a` and `b`
+pub fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn checked(value: Option<i128>) -> i128 {
+    value.expect("linear algebra overflowed i128")
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational {
+        numerator: 0,
+        denominator: 1,
+    };
+    pub const ONE: Rational = Rational {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// Panics if the denominator is 0
+    pub fn new(numerator: i128, denominator: i128) -> Rational {
+        assert!(denominator != 0, "a rational can't have a denominator of 0");
+        let divisor = gcd(numerator, denominator).max(1) * denominator.signum();
+        Rational {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    /// 1 over this. Panics if it's 0
+    pub fn recip(&self) -> Rational {
+        Rational::new(self.denominator, self.numerator)
+    }
+
+    /// The value if it's a whole number
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.denominator == 1).then_some(self.numerator)
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Rational::ZERO
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Rational {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Rational) -> Rational {
+        // Over the lowest common denominator, to keep the numbers small
+        let divisor = gcd(self.denominator, rhs.denominator);
+        let left = checked(self.numerator.checked_mul(rhs.denominator / divisor));
+        let right = checked(rhs.numerator.checked_mul(self.denominator / divisor));
+        Rational::new(
+            checked(left.checked_add(right)),
+            checked((self.denominator / divisor).checked_mul(rhs.denominator)),
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Rational) -> Rational {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Rational) -> Rational {
+        // Cancelling across first means nothing bigger than the result is ever made
+        let a = gcd(self.numerator, rhs.denominator).max(1);
+        let b = gcd(rhs.numerator, self.denominator).max(1);
+        Rational::new(
+            checked((self.numerator / a).checked_mul(rhs.numerator / b)),
+            checked((self.denominator / b).checked_mul(rhs.denominator / a)),
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    /// Panics when dividing by 0
+    fn div(self, rhs: Rational) -> Rational {
+        Mul::mul(self, rhs.recip())
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (*self - *other).numerator.cmp(&0)
+    }
+}
+
+fn to_rationals(matrix: &[Vec<i128>]) -> Vec<Vec<Rational>> {
+    matrix
+        .iter()
+        .map(|row| row.iter().map(|&value| Rational::from(value)).collect())
+        .collect()
+}
+
+/// Gauss-Jordan elimination, returning the reduced matrix and which column each row's pivot is
+/// in. Rows past the last pivot are all zero.
+pub fn reduced_row_echelon(matrix: &[Vec<i128>]) -> (Vec<Vec<Rational>>, Vec<usize>) {
+    let mut rows = to_rationals(matrix);
+    let columns = rows.first().map_or(0, |row| row.len());
+    let mut pivots = vec![];
+    for column in 0..columns {
+        let row = pivots.len();
+        let Some(pivot) = (row..rows.len()).find(|&r| !rows[r][column].is_zero()) else {
+            continue;
+        };
+        rows.swap(row, pivot);
+        let scale = rows[row][column];
+        for value in rows[row].iter_mut() {
+            *value = *value / scale;
+        }
+        let pivot_row = rows[row].clone();
+        for (other, values) in rows.iter_mut().enumerate() {
+            let factor = values[column];
+            if other == row || factor.is_zero() {
+                continue;
+            }
+            for (value, &pivot_value) in values[column..].iter_mut().zip(&pivot_row[column..]) {
+                *value = *value - factor * pivot_value;
+            }
+        }
+        pivots.push(column);
+    }
+    (rows, pivots)
+}
+
+/// The determinant of a square matrix, by Bareiss's algorithm so every step stays an integer
+pub fn determinant(matrix: &[Vec<i128>]) -> i128 {
+    let size = matrix.len();
+    assert!(
+        matrix.iter().all(|row| row.len() == size),
+        "only square matrices have a determinant"
+    );
+    let mut rows = matrix.to_vec();
+    let mut sign = 1;
+    let mut previous_pivot = 1;
+    for k in 0..size {
+        let Some(pivot) = (k..size).find(|&r| rows[r][k] != 0) else {
+            return 0;
+        };
+        if pivot != k {
+            rows.swap(pivot, k);
+            sign = -sign;
+        }
+        for i in (k + 1)..size {
+            for j in (k + 1)..size {
+                let cross = checked(
+                    checked(rows[i][j].checked_mul(rows[k][k]))
+                        .checked_sub(checked(rows[i][k].checked_mul(rows[k][j]))),
+                );
+                // Bareiss guarantees this divides exactly
+                rows[i][j] = cross / previous_pivot;
+            }
+        }
+        previous_pivot = rows[k][k];
+    }
+    sign * rows.last().map_or(1, |row| row[size - 1])
+}
+
+pub fn rank(matrix: &[Vec<i128>]) -> usize {
+    reduced_row_echelon(matrix).1.len()
+}
+
+/// The `x` with `a x = b`, if there is exactly one
+pub fn solve(a: &[Vec<i128>], b: &[i128]) -> Option<Vec<Rational>> {
+    let unknowns = a.first().map_or(0, |row| row.len());
+    let augmented: Vec<Vec<i128>> = a
+        .iter()
+        .zip(b)
+        .map(|(row, &value)| row.iter().copied().chain([value]).collect())
+        .collect();
+    let (rows, pivots) = reduced_row_echelon(&augmented);
+    // A pivot in the last column means 0 = something, and too few pivots means there's a
+    // whole family of solutions
+    if pivots.len() != unknowns || pivots.contains(&unknowns) {
+        return None;
+    }
+    Some(rows[..unknowns].iter().map(|row| row[unknowns]).collect())
+}
+
+/// A basis for every `x` with `matrix x = 0`. Empty if only `x = 0` works.
+pub fn null_space(matrix: &[Vec<i128>]) -> Vec<Vec<Rational>> {
+    let columns = matrix.first().map_or(0, |row| row.len());
+    let (rows, pivots) = reduced_row_echelon(matrix);
+    (0..columns)
+        .filter(|column| !pivots.contains(column))
+        .map(|free| {
+            let mut vector = vec![Rational::ZERO; columns];
+            vector[free] = Rational::ONE;
+            for (row, &pivot) in pivots.iter().enumerate() {
+                vector[pivot] = -rows[row][free];
+            }
+            vector
+        })
+        .collect()
+}
+
+/// Cramer's rule, for small square systems where a few determinants are cheaper than an
+/// elimination. `None` if the matrix is singular.
+pub fn cramer(a: &[Vec<i128>], b: &[i128]) -> Option<Vec<Rational>> {
+    let denominator = determinant(a);
+    if denominator == 0 {
+        return None;
+    }
+    let solution = (0..a.len())
+        .map(|column| {
+            let replaced: Vec<Vec<i128>> = a
+                .iter()
+                .zip(b)
+                .map(|(row, &value)| {
+                    let mut row = row.clone();
+                    row[column] = value;
+                    row
+                })
+                .collect();
+            Rational::new(determinant(&replaced), denominator)
+        })
+        .collect();
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(6, gcd(-12, 18));
+        assert_eq!(6, gcd(12, -18));
+        assert_eq!(7, gcd(0, -7));
+    }
+
+    #[test]
+    fn test_rational_arithmetic() {
+        let half = Rational::new(2, 4);
+        assert_eq!(Rational::new(-1, -2), half);
+        assert_eq!(1, half.numerator());
+        assert_eq!(2, half.denominator());
+        assert_eq!(Rational::new(5, 6), half + Rational::new(1, 3));
+        assert_eq!(Rational::new(1, 6), half - Rational::new(1, 3));
+        assert_eq!(Rational::new(-1, 3), half * Rational::new(-2, 3));
+        assert_eq!(Rational::from(3), Rational::new(3, 2) / half);
+        assert_eq!(Some(3), (Rational::new(3, 2) / half).to_integer());
+        assert_eq!(None, half.to_integer());
+        assert!(Rational::new(-1, 2) < Rational::new(1, 3));
+        assert_eq!("-7/2", Rational::new(7, -2).to_string());
+    }
+
+    #[test]
+    fn test_determinant() {
+        assert_eq!(-2, determinant(&[vec![1, 2], vec![3, 4]]));
+        assert_eq!(
+            -306,
+            determinant(&[vec![6, 1, 1], vec![4, -2, 5], vec![2, 8, 7]])
+        );
+        // Needs a row swap to find a pivot
+        assert_eq!(-1, determinant(&[vec![0, 1], vec![1, 0]]));
+        assert_eq!(0, determinant(&[vec![1, 2], vec![2, 4]]));
+    }
+
+    #[test]
+    fn test_rank() {
+        assert_eq!(2, rank(&[vec![1, 2, 3], vec![2, 4, 6], vec![1, 0, 1]]));
+        assert_eq!(0, rank(&[vec![0, 0], vec![0, 0]]));
+    }
+
+    #[test]
+    fn test_solve() {
+        // The first claw machine from 2024 day 13
+        let a = [vec![94, 22], vec![34, 67]];
+        let solution = solve(&a, &[8400, 5400]).unwrap();
+        assert_eq!(vec![Rational::from(80), Rational::from(40)], solution);
+        assert_eq!(Some(solution), cramer(&a, &[8400, 5400]));
+
+        // Its second one has a solution, but not a whole number one
+        let solution = solve(&[vec![26, 67], vec![66, 21]], &[12748, 12176]).unwrap();
+        assert!(solution.iter().any(|value| value.to_integer().is_none()));
+
+        assert_eq!(None, solve(&[vec![1, 2], vec![2, 4]], &[3, 7]));
+        assert_eq!(None, cramer(&[vec![1, 2], vec![2, 4]], &[3, 6]));
+        assert_eq!(None, solve(&[vec![1, 2], vec![2, 4]], &[3, 6]));
+    }
+
+    #[test]
+    fn test_null_space() {
+        let matrix = [vec![1, 2, 3], vec![2, 4, 6]];
+        let basis = null_space(&matrix);
+        assert_eq!(2, basis.len());
+        for vector in basis {
+            for row in matrix.iter() {
+                let product = row
+                    .iter()
+                    .zip(vector.iter())
+                    .fold(Rational::ZERO, |sum, (&a, &b)| sum + Rational::from(a) * b);
+                assert_eq!(Rational::ZERO, product);
+            }
+        }
+        assert!(null_space(&[vec![1, 0], vec![0, 1]]).is_empty());
+    }
+}
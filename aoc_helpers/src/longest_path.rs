@@ -0,0 +1,301 @@
+//! The longest way through a maze without stepping on any tile twice.
+//!
+//! That's NP-hard in general, so it's only quick because mazes are mostly corridors. The maze is
+//! contracted to the junctions, where there's a choice of way, with an edge for each corridor
+//! between them. There are few enough junctions to keep the visited ones in a `u64` bitmask,
+//! and a search that gives up on any path that couldn't beat the best one so far even if it
+//! took the longest way out of every junction it has left.
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use crate::direction::Direction4;
+use crate::grid::Grid;
+use crate::point2d::Point2D;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JunctionGraph {
+    /// The start, then the end, then the rest
+    junctions: Vec<Point2D>,
+    /// Out of each junction, as the junction the corridor leads to and its length in steps
+    edges: Vec<Vec<(usize, usize)>>,
+}
+
+const START: usize = 0;
+const END: usize = 1;
+
+impl JunctionGraph {
+    /// `exits` gives the directions a tile can be left in: none for a wall, all 4 for open
+    /// ground, or fewer for something one way like a slope. A junction is any open tile with
+    /// more than 2 open neighbours, and a corridor is only an edge if it can be followed all the
+    /// way along.
+    pub fn from_grid<T>(
+        grid: &Grid<T>,
+        start: Point2D,
+        end: Point2D,
+        exits: impl Fn(&T) -> Vec<Direction4>,
+    ) -> JunctionGraph {
+        let tile_exits = |point: &Point2D| grid.get(point).map(&exits).unwrap_or_default();
+        let is_open = |point: &Point2D| !tile_exits(point).is_empty();
+        let mut junctions = vec![start, end];
+        junctions.extend(grid.points().filter(|point| {
+            *point != start
+                && *point != end
+                && is_open(point)
+                && grid.neighbours(point).filter(is_open).count() > 2
+        }));
+        let indices: HashMap<Point2D, usize> = junctions
+            .iter()
+            .enumerate()
+            .map(|(index, junction)| (*junction, index))
+            .collect();
+
+        let follow_corridor = |junction: Point2D, direction: Direction4| {
+            let mut previous = junction;
+            let mut current = junction + direction.as_point();
+            let mut length = 1;
+            while !indices.contains_key(&current) {
+                if !is_open(&current) {
+                    return None;
+                }
+                let next = tile_exits(&current)
+                    .into_iter()
+                    .map(|direction| current + direction.as_point())
+                    .find(|next| *next != previous && is_open(next))?;
+                (previous, current) = (current, next);
+                length += 1;
+            }
+            Some((indices[&current], length))
+        };
+        let edges = junctions
+            .iter()
+            .map(|junction| {
+                tile_exits(junction)
+                    .into_iter()
+                    .filter_map(|direction| follow_corridor(*junction, direction))
+                    .collect()
+            })
+            .collect();
+        JunctionGraph { junctions, edges }
+    }
+
+    /// The junctions, including the start and end
+    pub fn junctions(&self) -> &[Point2D] {
+        &self.junctions
+    }
+
+    /// The number of steps in the longest way from the start to the end that doesn't go
+    /// anywhere twice, or `None` if the end can't be reached. Panics with more than 64
+    /// junctions.
+    pub fn longest_path(&self) -> Option<usize> {
+        let search = Search::new(self);
+        search.search(search.first());
+        search.longest()
+    }
+
+    /// `longest_path` spread over every core
+    pub fn longest_path_parallel(&self) -> Option<usize> {
+        let search = Search::new(self);
+        let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+        // Lots more starting points than threads, so they all finish at about the same time
+        let mut tasks = vec![search.first()];
+        while !tasks.is_empty() && tasks.len() < threads * 16 {
+            tasks = tasks
+                .iter()
+                .filter(|partial| !search.finished(partial))
+                .flat_map(|partial| search.next_steps(*partial))
+                .collect();
+        }
+        let next_task = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            for _ in 0..threads {
+                scope.spawn(|| {
+                    while let Some(task) = tasks.get(next_task.fetch_add(1, Ordering::Relaxed)) {
+                        search.search(*task);
+                    }
+                });
+            }
+        });
+        search.longest()
+    }
+}
+
+/// A path from the start that's got as far as `junction`
+#[derive(Debug, Clone, Copy)]
+struct Partial {
+    junction: usize,
+    visited: u64,
+    length: usize,
+    /// The most steps that the junctions not visited yet could add
+    remaining: usize,
+}
+
+struct Search<'a> {
+    graph: &'a JunctionGraph,
+    /// The longest corridor out of each junction, except the end which is never left
+    longest_exit: Vec<usize>,
+    /// If every way into the end is from one junction, getting there means going straight
+    /// to the end: going anywhere else would leave the end cut off
+    last_corridor: Option<(usize, usize)>,
+    /// One more than the longest path found so far, so 0 is none found
+    best: AtomicUsize,
+}
+
+impl<'a> Search<'a> {
+    fn new(graph: &'a JunctionGraph) -> Search<'a> {
+        assert!(
+            graph.junctions.len() <= 64,
+            "{} junctions won't fit in a u64",
+            graph.junctions.len()
+        );
+        let mut longest_exit: Vec<usize> = graph
+            .edges
+            .iter()
+            .map(|edges| edges.iter().map(|&(_, length)| length).max().unwrap_or(0))
+            .collect();
+        longest_exit[END] = 0;
+        let mut into_end = graph.edges.iter().enumerate().flat_map(|(from, edges)| {
+            edges
+                .iter()
+                .filter(|&&(to, _)| to == END)
+                .map(move |&(_, length)| (from, length))
+        });
+        let last_corridor = match (into_end.next(), into_end.next()) {
+            (Some(corridor), None) => Some(corridor),
+            _ => None,
+        };
+        Search {
+            graph,
+            longest_exit,
+            last_corridor,
+            best: AtomicUsize::new(0),
+        }
+    }
+
+    fn first(&self) -> Partial {
+        Partial {
+            junction: START,
+            visited: 1 << START,
+            length: 0,
+            remaining: self.longest_exit.iter().sum::<usize>() - self.longest_exit[START],
+        }
+    }
+
+    fn longest(&self) -> Option<usize> {
+        self.best.load(Ordering::Relaxed).checked_sub(1)
+    }
+
+    /// Records the path if it's as far as it can go
+    fn finished(&self, partial: &Partial) -> bool {
+        let length = match self.last_corridor {
+            _ if partial.junction == END => partial.length,
+            Some((from, length)) if partial.junction == from => partial.length + length,
+            _ => return false,
+        };
+        self.best.fetch_max(length + 1, Ordering::Relaxed);
+        true
+    }
+
+    fn next_steps(&self, partial: Partial) -> impl Iterator<Item = Partial> + '_ {
+        self.graph.edges[partial.junction]
+            .iter()
+            .filter(move |&&(next, _)| partial.visited & (1 << next) == 0)
+            .map(move |&(next, length)| Partial {
+                junction: next,
+                visited: partial.visited | (1 << next),
+                length: partial.length + length,
+                remaining: partial.remaining - self.longest_exit[next],
+            })
+    }
+
+    fn search(&self, partial: Partial) {
+        if self.finished(&partial) {
+            return;
+        }
+        let bound = partial.length + self.longest_exit[partial.junction] + partial.remaining;
+        if bound < self.best.load(Ordering::Relaxed) {
+            return;
+        }
+        for next in self.next_steps(partial) {
+            self.search(next);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const MAZE: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
+
+    fn slopes(tile: &char) -> Vec<Direction4> {
+        match tile {
+            '#' => vec![],
+            '^' => vec![Direction4::Up],
+            '>' => vec![Direction4::Right],
+            'v' => vec![Direction4::Down],
+            '<' => vec![Direction4::Left],
+            _ => Direction4::ALL.to_vec(),
+        }
+    }
+
+    fn no_slopes(tile: &char) -> Vec<Direction4> {
+        match tile {
+            '#' => vec![],
+            _ => Direction4::ALL.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_longest_path() {
+        let grid = Grid::parse(MAZE, Some).unwrap();
+        let start = Point2D::new(1, 0);
+        let end = Point2D::new(21, 22);
+        let graph = JunctionGraph::from_grid(&grid, start, end, slopes);
+        assert_eq!(9, graph.junctions().len());
+        assert_eq!(Some(94), graph.longest_path());
+        let graph = JunctionGraph::from_grid(&grid, start, end, no_slopes);
+        assert_eq!(Some(154), graph.longest_path());
+        assert_eq!(Some(154), graph.longest_path_parallel());
+    }
+
+    #[test]
+    fn test_unreachable_end() {
+        let grid = Grid::parse(".#.\n.#.\n...\n", Some).unwrap();
+        let one_way = |tile: &char| match tile {
+            '#' => vec![],
+            _ => vec![Direction4::Down],
+        };
+        let graph =
+            JunctionGraph::from_grid(&grid, Point2D::new(0, 0), Point2D::new(2, 0), one_way);
+        assert_eq!(None, graph.longest_path());
+        let graph =
+            JunctionGraph::from_grid(&grid, Point2D::new(0, 0), Point2D::new(2, 0), no_slopes);
+        assert_eq!(Some(6), graph.longest_path_parallel());
+    }
+}
@@ -0,0 +1,124 @@
+//! Memoisation for recursive counting ("how many ways can this end?"), so a solver can be
+//! written as the plain recursion and still only work out each subproblem once.
+//!
+//! The function is given the `Memo` itself along with the key, and recurses by calling
+//! `memo.get` on smaller keys:
+//!
+//! ```
+//! use aoc_helpers::memo::Memo;
+//!
+//! let mut fibonacci = Memo::new(|memo, &n: &u64| {
+//!     if n < 2 {
+//!         n
+//!     } else {
+//!         memo.get(n - 1) + memo.get(n - 2)
+//!     }
+//! });
+//! assert_eq!(12586269025, fibonacci.get(50));
+//! ```
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// How often `Memo::get` found its answer already worked out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+type MemoFunction<'a, K, V> = dyn Fn(&mut Memo<'a, K, V>, &K) -> V + 'a;
+
+pub struct Memo<'a, K, V> {
+    cache: HashMap<K, V>,
+    // An Rc so the function can be called while it's given the Memo mutably
+    function: Rc<MemoFunction<'a, K, V>>,
+    stats: MemoStats,
+}
+
+impl<'a, K: Eq + Hash + Clone, V: Clone> Memo<'a, K, V> {
+    pub fn new(function: impl Fn(&mut Memo<'a, K, V>, &K) -> V + 'a) -> Memo<'a, K, V> {
+        Memo {
+            cache: HashMap::new(),
+            function: Rc::new(function),
+            stats: MemoStats::default(),
+        }
+    }
+
+    /// The function's value for `key`, working it out (and anything it recurses into) only if
+    /// it hasn't been asked for before
+    pub fn get(&mut self, key: K) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let function = Rc::clone(&self.function);
+        let value = function(self, &key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+
+    /// The number of keys worked out so far
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_memo_counts_each_key_once() {
+        // Lattice paths from (x, y) back to (0, 0), moving left or up
+        let calls = std::cell::Cell::new(0);
+        let mut paths = Memo::new(|memo, &(x, y): &(u64, u64)| {
+            calls.set(calls.get() + 1);
+            if x == 0 || y == 0 {
+                1
+            } else {
+                memo.get((x - 1, y)) + memo.get((x, y - 1))
+            }
+        });
+        assert_eq!(184756, paths.get((10, 10)));
+        assert_eq!(120, paths.len());
+        assert_eq!(120, calls.get());
+        assert_eq!(
+            MemoStats {
+                hits: 81,
+                misses: 120
+            },
+            paths.stats()
+        );
+        assert_eq!(252, paths.get((5, 5)));
+        assert_eq!(82, paths.stats().hits);
+    }
+
+    #[test]
+    fn test_memo_borrows_its_surroundings() {
+        let words = ["a", "ab", "b"];
+        let target = "abab";
+        let mut ways = Memo::new(|memo, &start: &usize| -> usize {
+            if start == target.len() {
+                return 1;
+            }
+            words
+                .iter()
+                .filter(|word| target[start..].starts_with(*word))
+                .map(|word| memo.get(start + word.len()))
+                .sum()
+        });
+        assert!(ways.is_empty());
+        assert_eq!(4, ways.get(0));
+    }
+}
@@ -0,0 +1,210 @@
+//! The fewest edges to remove to split an undirected graph in two.
+//!
+//! By max-flow min-cut, the fewest edges separating two nodes is the most paths between them that
+//! share no edge, which Edmonds-Karp finds one shortest path at a time. Every split leaves the
+//! first node on one side and some other node on the other, so the smallest split overall is the
+//! smallest of those between the first node and each of the others. Each of those can give up
+//! as soon as it has as many paths as the best cut found so far, so once a small cut turns up
+//! the rest are quick.
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut<N> {
+    /// The edges that cross from one side to the other, as they were given
+    pub edges: Vec<(N, N)>,
+    pub side: Vec<N>,
+    pub other_side: Vec<N>,
+}
+
+impl<N> Cut<N> {
+    /// The number of nodes on each side
+    pub fn sizes(&self) -> (usize, usize) {
+        (self.side.len(), self.other_side.len())
+    }
+}
+
+/// Each edge is a pair of arcs, one each way, stored next to each other so an arc's reverse is
+/// at its index with the last bit flipped. Pushing flow along one gives the same amount of
+/// capacity back to the other.
+struct FlowGraph {
+    arc_ends: Vec<usize>,
+    capacities: Vec<usize>,
+    /// The arcs out of each node
+    arcs: Vec<Vec<usize>>,
+}
+
+impl FlowGraph {
+    fn new(node_count: usize, edges: &[(usize, usize)]) -> FlowGraph {
+        let mut graph = FlowGraph {
+            arc_ends: Vec::with_capacity(edges.len() * 2),
+            capacities: vec![1; edges.len() * 2],
+            arcs: vec![vec![]; node_count],
+        };
+        for &(a, b) in edges.iter() {
+            graph.arcs[a].push(graph.arc_ends.len());
+            graph.arc_ends.push(b);
+            graph.arcs[b].push(graph.arc_ends.len());
+            graph.arc_ends.push(a);
+        }
+        graph
+    }
+
+    /// The shortest path from `source` to `sink` through arcs with capacity left, as the arcs
+    /// taken, or which nodes could be reached if there isn't one
+    fn augmenting_path(
+        &self,
+        capacities: &[usize],
+        source: usize,
+        sink: usize,
+    ) -> Result<Vec<usize>, Vec<bool>> {
+        let mut arc_into: Vec<Option<usize>> = vec![None; self.arcs.len()];
+        let mut reached = vec![false; self.arcs.len()];
+        reached[source] = true;
+        let mut queue = VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            for &arc in self.arcs[node].iter() {
+                let next = self.arc_ends[arc];
+                if capacities[arc] > 0 && !reached[next] {
+                    reached[next] = true;
+                    arc_into[next] = Some(arc);
+                    if next == sink {
+                        let mut path = vec![];
+                        let mut current = sink;
+                        while let Some(arc) = arc_into[current] {
+                            path.push(arc);
+                            current = self.arc_ends[arc ^ 1];
+                        }
+                        return Ok(path);
+                    }
+                    queue.push_back(next);
+                }
+            }
+        }
+        Err(reached)
+    }
+
+    /// The nodes on `source`'s side of the smallest cut between `source` and `sink`, along with
+    /// its size, or `None` if it would be `limit` edges or more
+    fn minimum_cut(&self, source: usize, sink: usize, limit: usize) -> Option<(usize, Vec<bool>)> {
+        let mut capacities = self.capacities.clone();
+        let mut flow = 0;
+        loop {
+            match self.augmenting_path(&capacities, source, sink) {
+                Ok(path) => {
+                    flow += 1;
+                    if flow >= limit {
+                        return None;
+                    }
+                    for arc in path {
+                        capacities[arc] -= 1;
+                        capacities[arc ^ 1] += 1;
+                    }
+                }
+                Err(reached) => return Some((flow, reached)),
+            }
+        }
+    }
+}
+
+/// The smallest set of edges that splits the graph in two, or `None` if there aren't 2 nodes to
+/// split. A graph that's already in pieces gives a cut with no edges.
+pub fn minimum_cut<N: Eq + Hash + Clone>(
+    edges: impl IntoIterator<Item = (N, N)>,
+) -> Option<Cut<N>> {
+    let mut nodes = vec![];
+    let mut indices = HashMap::new();
+    let mut index_of = |node: N| {
+        *indices.entry(node.clone()).or_insert_with(|| {
+            nodes.push(node);
+            nodes.len() - 1
+        })
+    };
+    let edges: Vec<(usize, usize)> = edges
+        .into_iter()
+        .map(|(a, b)| (index_of(a), index_of(b)))
+        .collect();
+    if nodes.len() < 2 {
+        return None;
+    }
+
+    let graph = FlowGraph::new(nodes.len(), &edges);
+    let mut best: Option<(usize, Vec<bool>)> = None;
+    for sink in 1..nodes.len() {
+        let limit = best.as_ref().map_or(usize::MAX, |(size, _)| *size);
+        if let Some(cut) = graph.minimum_cut(0, sink, limit) {
+            let disconnected = cut.0 == 0;
+            best = Some(cut);
+            if disconnected {
+                break;
+            }
+        }
+    }
+    let (_, on_side) = best?;
+
+    let (side, other_side): (Vec<usize>, Vec<usize>) =
+        (0..nodes.len()).partition(|&index| on_side[index]);
+    let to_nodes = |indices: Vec<usize>| -> Vec<N> {
+        indices
+            .into_iter()
+            .map(|index| nodes[index].clone())
+            .collect()
+    };
+    Some(Cut {
+        edges: edges
+            .iter()
+            .filter(|&&(a, b)| on_side[a] != on_side[b])
+            .map(|&(a, b)| (nodes[a].clone(), nodes[b].clone()))
+            .collect(),
+        side: to_nodes(side),
+        other_side: to_nodes(other_side),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Two 4-cliques, with 2 edges between them
+    fn cliques() -> Vec<(&'static str, &'static str)> {
+        let left = ["a", "b", "c", "d"];
+        let right = ["w", "x", "y", "z"];
+        let mut edges = vec![("a", "w"), ("x", "c")];
+        for side in [left, right] {
+            for (i, a) in side.iter().enumerate() {
+                for b in side[i + 1..].iter() {
+                    edges.push((a, b));
+                }
+            }
+        }
+        edges
+    }
+
+    #[test]
+    fn test_minimum_cut() {
+        let cut = minimum_cut(cliques()).unwrap();
+        assert_eq!(vec![("a", "w"), ("x", "c")], cut.edges);
+        assert_eq!((4, 4), cut.sizes());
+        let mut side = cut.side.clone();
+        side.sort();
+        assert_eq!(vec!["a", "b", "c", "d"], side);
+    }
+
+    #[test]
+    fn test_minimum_cut_edge_cases() {
+        // A leaf is the easiest thing to cut off
+        let mut edges = cliques();
+        edges.push(("z", "leaf"));
+        let cut = minimum_cut(edges).unwrap();
+        assert_eq!(vec![("z", "leaf")], cut.edges);
+        assert_eq!(1, cut.sizes().0.min(cut.sizes().1));
+
+        let cut = minimum_cut([(1, 2), (2, 3), (4, 5)]).unwrap();
+        assert!(cut.edges.is_empty());
+        assert_eq!((3, 2), cut.sizes());
+
+        assert_eq!(None, minimum_cut([(1, 1)]));
+        assert_eq!(None, minimum_cut(Vec::<(u8, u8)>::new()));
+    }
+}
@@ -0,0 +1,143 @@
+pub fn greatest_common_divisor(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        greatest_common_divisor(b, a % b)
+    }
+}
+
+pub fn least_common_multiple(a: usize, b: usize) -> usize {
+    a * (b / greatest_common_divisor(a, b))
+}
+
+/// The least common multiple of every value (1 if there are none), or `None` if it doesn't fit
+/// in a `u128`
+pub fn least_common_multiple_of(values: impl IntoIterator<Item = u128>) -> Option<u128> {
+    values.into_iter().try_fold(1u128, |lcm, value| {
+        if value == 0 {
+            return Some(0);
+        }
+        let divisor = greatest_common_divisor_u128(lcm, value);
+        (lcm / divisor.max(1)).checked_mul(value)
+    })
+}
+
+fn greatest_common_divisor_u128(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        greatest_common_divisor_u128(b, a % b)
+    }
+}
+
+/// The extended Euclidean algorithm: `(g, x, y)` where `g` is the (non-negative) greatest common
+/// divisor of `a` and `b`, and `a * x + b * y == g`
+pub fn extended_greatest_common_divisor(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a.abs(), a.signum(), 0)
+    } else {
+        let (g, x, y) = extended_greatest_common_divisor(b, a.rem_euclid(b));
+        (g, y, x - a.div_euclid(b) * y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x == 1 (mod modulus)`, if `a` and `modulus` are coprime
+pub fn modular_inverse(a: i128, modulus: i128) -> Option<i128> {
+    let (g, x, _) = extended_greatest_common_divisor(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// `base ^ exponent % modulus`, by repeated squaring
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result as u64
+}
+
+/// The Chinese Remainder Theorem. Takes `(remainder, modulus)` pairs and finds the smallest
+/// non-negative `x` with `x == remainder (mod modulus)` for all of them, returned as `(x, lcm)`
+/// since every `x + n * lcm` works too.
+///
+/// The moduli don't need to be coprime, but then the remainders have to agree wherever the
+/// moduli overlap, or there's no answer and this returns `None`. Also `None` if the lcm of the
+/// moduli overflows.
+pub fn chinese_remainder(
+    congruences: impl IntoIterator<Item = (i128, i128)>,
+) -> Option<(i128, i128)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(x, lcm), (remainder, modulus)| {
+            let (g, inverse, _) = extended_greatest_common_divisor(lcm, modulus);
+            let difference = remainder - x;
+            if difference % g != 0 {
+                return None;
+            }
+            // x + lcm * k == remainder (mod modulus), so k == difference / g * inverse
+            // (mod modulus / g)
+            let step = modulus / g;
+            let k =
+                ((difference / g).rem_euclid(step)).checked_mul(inverse.rem_euclid(step))? % step;
+            let combined = (lcm / g).checked_mul(modulus)?;
+            Some((
+                x.checked_add(lcm.checked_mul(k)?)?.rem_euclid(combined),
+                combined,
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_least_common_multiple_of() {
+        assert_eq!(Some(60), least_common_multiple_of([4, 6, 10, 15]));
+        assert_eq!(Some(1), least_common_multiple_of([]));
+        assert_eq!(None, least_common_multiple_of([u128::MAX, u128::MAX - 1]));
+    }
+
+    #[test]
+    fn test_extended_greatest_common_divisor() {
+        for (a, b) in [(240, 46), (-240, 46), (17, 5), (0, 7), (7, 0)] {
+            let (g, x, y) = extended_greatest_common_divisor(a, b);
+            assert_eq!(
+                greatest_common_divisor(a.unsigned_abs() as usize, b as usize),
+                g as usize
+            );
+            assert_eq!(g, a * x + b * y);
+        }
+    }
+
+    #[test]
+    fn test_modular_inverse() {
+        assert_eq!(Some(4), modular_inverse(3, 11));
+        assert_eq!(Some(7), modular_inverse(-3, 11));
+        assert_eq!(None, modular_inverse(6, 9));
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(445, mod_pow(4, 13, 497));
+        assert_eq!(0, mod_pow(4, 13, 1));
+        // Big enough that squaring would overflow a u64
+        assert_eq!(1, mod_pow(3, 18446744073709551556, 18446744073709551557));
+    }
+
+    #[test]
+    fn test_chinese_remainder() {
+        assert_eq!(Some((23, 105)), chinese_remainder([(2, 3), (3, 5), (2, 7)]));
+        // Not coprime, but they agree mod 2
+        assert_eq!(Some((7, 12)), chinese_remainder([(3, 4), (1, 6)]));
+        assert_eq!(None, chinese_remainder([(0, 4), (1, 6)]));
+        assert_eq!(Some((0, 1)), chinese_remainder([]));
+    }
+}
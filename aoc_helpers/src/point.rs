@@ -0,0 +1,210 @@
+//! What `Point2D` and `Point3D` have in common: a fixed number of coordinates, all of the same
+//! integer type, that add, subtract and scale like vectors.
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// The integer types a point can be made of
+pub trait Coordinate:
+    Copy
+    + Debug
+    + Display
+    + Default
+    + Eq
+    + Ord
+    + Hash
+    + Sum
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn from_usize(value: usize) -> Self;
+
+    /// The absolute value, as a `usize`
+    fn magnitude(self) -> usize;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn from_usize(value: usize) -> Self {
+                    value as $t
+                }
+
+                fn magnitude(self) -> usize {
+                    self.unsigned_abs() as usize
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i32, i64, isize);
+
+/// A point with `N` coordinates of type `T`, for code that works in any number of dimensions
+pub trait Point<T: Coordinate, const N: usize>:
+    Copy
+    + Eq
+    + Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Neg<Output = Self>
+    + Mul<T, Output = Self>
+    + Div<T, Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    fn from_coordinates(coordinates: [T; N]) -> Self;
+
+    fn coordinates(&self) -> [T; N];
+
+    /// The point with every coordinate 0
+    fn origin() -> Self {
+        Self::from_coordinates([T::ZERO; N])
+    }
+
+    /// The points one step away along each axis, negative direction first
+    fn orthogonal_neighbours(&self) -> Vec<Self> {
+        (0..N)
+            .flat_map(|axis| {
+                [-T::ONE, T::ONE].map(|step| {
+                    let mut coordinates = self.coordinates();
+                    coordinates[axis] += step;
+                    Self::from_coordinates(coordinates)
+                })
+            })
+            .collect()
+    }
+
+    fn manhattan_length(&self) -> usize {
+        self.coordinates().iter().map(|c| c.magnitude()).sum()
+    }
+}
+
+/// The operators every point type has, coordinate by coordinate
+macro_rules! impl_point_ops {
+    ($point:ident, $($field:ident),+) => {
+        impl<T: Coordinate> std::ops::Add for $point<T> {
+            type Output = $point<T>;
+
+            fn add(self, rhs: $point<T>) -> $point<T> {
+                $point { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl<T: Coordinate> std::ops::Add for &$point<T> {
+            type Output = $point<T>;
+
+            fn add(self, rhs: &$point<T>) -> $point<T> {
+                *self + *rhs
+            }
+        }
+
+        impl<T: Coordinate> std::ops::Sub for $point<T> {
+            type Output = $point<T>;
+
+            fn sub(self, rhs: $point<T>) -> $point<T> {
+                $point { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<T: Coordinate> std::ops::Sub for &$point<T> {
+            type Output = $point<T>;
+
+            fn sub(self, rhs: &$point<T>) -> $point<T> {
+                *self - *rhs
+            }
+        }
+
+        impl<T: Coordinate> std::ops::Neg for $point<T> {
+            type Output = $point<T>;
+
+            fn neg(self) -> $point<T> {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: Coordinate> std::ops::Neg for &$point<T> {
+            type Output = $point<T>;
+
+            fn neg(self) -> $point<T> {
+                -*self
+            }
+        }
+
+        impl<T: Coordinate> std::ops::Mul<T> for $point<T> {
+            type Output = $point<T>;
+
+            fn mul(self, scalar: T) -> $point<T> {
+                $point { $($field: self.$field * scalar),+ }
+            }
+        }
+
+        impl<T: Coordinate> std::ops::Mul<T> for &$point<T> {
+            type Output = $point<T>;
+
+            fn mul(self, scalar: T) -> $point<T> {
+                *self * scalar
+            }
+        }
+
+        /// Rounds towards 0, like integer division does
+        impl<T: Coordinate> std::ops::Div<T> for $point<T> {
+            type Output = $point<T>;
+
+            fn div(self, scalar: T) -> $point<T> {
+                $point { $($field: self.$field / scalar),+ }
+            }
+        }
+
+        impl<T: Coordinate> std::ops::Div<T> for &$point<T> {
+            type Output = $point<T>;
+
+            fn div(self, scalar: T) -> $point<T> {
+                *self / scalar
+            }
+        }
+
+        impl<T: Coordinate> std::ops::AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: $point<T>) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl<T: Coordinate> std::ops::SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: $point<T>) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+
+        impl<T: Coordinate> std::ops::MulAssign<T> for $point<T> {
+            fn mul_assign(&mut self, scalar: T) {
+                $(self.$field *= scalar;)+
+            }
+        }
+
+        /// Rounds towards 0, like integer division does
+        impl<T: Coordinate> std::ops::DivAssign<T> for $point<T> {
+            fn div_assign(&mut self, scalar: T) {
+                $(self.$field /= scalar;)+
+            }
+        }
+    };
+}
+
+pub(crate) use impl_point_ops;
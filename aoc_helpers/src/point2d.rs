@@ -0,0 +1,167 @@
+use std::{fmt::Debug, ops::Range};
+
+use itertools::Itertools;
+
+use crate::point::{impl_point_ops, Coordinate, Point};
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord, Default)]
+pub struct Point2D<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Debug> Debug for Point2D<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?},{:?})", self.x, self.y)
+    }
+}
+
+pub trait Neighbours<T = isize> {
+    fn get_neighbours(&self) -> Vec<Point2D<T>>;
+    fn get_8_neighbours(&self) -> Vec<Point2D<T>>;
+}
+
+impl<T: Coordinate> Neighbours<T> for Point2D<T> {
+    fn get_neighbours(&self) -> Vec<Point2D<T>> {
+        [
+            (T::ZERO, -T::ONE),
+            (T::ZERO, T::ONE),
+            (-T::ONE, T::ZERO),
+            (T::ONE, T::ZERO),
+        ]
+        .map(|(x, y)| *self + Point2D { x, y })
+        .to_vec()
+    }
+
+    fn get_8_neighbours(&self) -> Vec<Point2D<T>> {
+        [
+            (-T::ONE, -T::ONE),
+            (T::ZERO, -T::ONE),
+            (T::ONE, -T::ONE),
+            (-T::ONE, T::ZERO),
+            (T::ONE, T::ZERO),
+            (-T::ONE, T::ONE),
+            (T::ZERO, T::ONE),
+            (T::ONE, T::ONE),
+        ]
+        .map(|(x, y)| *self + Point2D { x, y })
+        .to_vec()
+    }
+}
+
+impl<T: Coordinate> Point2D<T> {
+    pub fn new(x: T, y: T) -> Point2D<T> {
+        Point2D { x, y }
+    }
+
+    pub fn from_usize(x: usize, y: usize) -> Point2D<T> {
+        Point2D {
+            x: T::from_usize(x),
+            y: T::from_usize(y),
+        }
+    }
+
+    pub fn get_manhattan_distance(&self, other: &Point2D<T>) -> usize {
+        (self.x - other.x).magnitude() + (self.y - other.y).magnitude()
+    }
+
+    /// A quarter turn clockwise, with `y` growing downwards (so up turns to the right)
+    pub fn turn_right(&self) -> Point2D<T> {
+        Point2D {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// A quarter turn anticlockwise, with `y` growing downwards (so up turns to the left)
+    pub fn turn_left(&self) -> Point2D<T> {
+        Point2D {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+
+    pub fn iterate_x_y(x_range: Range<T>, y_range: Range<T>) -> Vec<Point2D<T>>
+    where
+        Range<T>: Iterator<Item = T> + Clone,
+    {
+        x_range
+            .cartesian_product(y_range)
+            .map(|(x, y)| Point2D { x, y })
+            .collect()
+    }
+
+    pub fn iterate_x_y_usize(x_range: Range<usize>, y_range: Range<usize>) -> Vec<Point2D<T>> {
+        x_range
+            .cartesian_product(y_range)
+            .map(|(x, y)| Point2D::from_usize(x, y))
+            .collect()
+    }
+}
+
+impl<T: Coordinate> Point<T, 2> for Point2D<T> {
+    fn from_coordinates([x, y]: [T; 2]) -> Point2D<T> {
+        Point2D { x, y }
+    }
+
+    fn coordinates(&self) -> [T; 2] {
+        [self.x, self.y]
+    }
+}
+
+impl_point_ops!(Point2D, x, y);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_operators() {
+        let mut point = Point2D { x: 3, y: -4 };
+        assert_eq!(Point2D { x: -3, y: 4 }, -point);
+        assert_eq!(Point2D { x: 6, y: -8 }, point * 2);
+        assert_eq!(Point2D { x: 1, y: -2 }, point / 2);
+        point += Point2D { x: 1, y: 1 };
+        assert_eq!(Point2D { x: 4, y: -3 }, point);
+        point -= Point2D { x: 4, y: 0 };
+        assert_eq!(Point2D { x: 0, y: -3 }, point);
+        assert_eq!(7, point.get_manhattan_distance(&Point2D { x: 4, y: 0 }));
+        point *= 3;
+        assert_eq!(Point2D { x: 0, y: -9 }, point);
+        point /= 2;
+        assert_eq!(Point2D { x: 0, y: -4 }, point);
+    }
+
+    #[test]
+    fn test_reference_operators() {
+        let point = Point2D { x: 3, y: -4 };
+        let other = Point2D { x: 1, y: 1 };
+        // As references, the way they'd come out of a collection
+        let (by_ref, other_by_ref) = (&point, &other);
+        assert_eq!(point + other, by_ref + other_by_ref);
+        assert_eq!(point - other, by_ref - other_by_ref);
+        assert_eq!(-point, -by_ref);
+        assert_eq!(point * 2, by_ref * 2);
+        assert_eq!(point / 2, by_ref / 2);
+    }
+
+    #[test]
+    fn test_other_coordinate_types() {
+        let point: Point2D<i32> = Point2D::from_usize(2, 5);
+        assert_eq!(Point2D { x: -5, y: 2 }, point.turn_right());
+        assert_eq!(Point2D { x: 5, y: -2 }, point.turn_left());
+        assert_eq!(4, point.get_neighbours().len());
+        let point: Point2D<i64> = Point2D::new(1 << 40, 0);
+        assert_eq!(1 << 41, (point * 2).manhattan_length() as i64);
+        assert_eq!(
+            vec![
+                Point2D { x: 0, y: 0 },
+                Point2D { x: 2, y: 0 },
+                Point2D { x: 1, y: -1 },
+                Point2D { x: 1, y: 1 }
+            ],
+            Point2D::<i64>::new(1, 0).orthogonal_neighbours()
+        );
+    }
+}
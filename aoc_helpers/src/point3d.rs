@@ -0,0 +1,118 @@
+use std::fmt::Debug;
+
+use crate::point::{impl_point_ops, Coordinate, Point};
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord, Default)]
+pub struct Point3D<T = isize> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Debug> Debug for Point3D<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?},{:?},{:?})", self.x, self.y, self.z)
+    }
+}
+
+pub trait Neighbours<T = isize> {
+    fn get_neighbours(&self) -> Vec<Point3D<T>>;
+}
+
+impl<T: Coordinate> Neighbours<T> for Point3D<T> {
+    fn get_neighbours(&self) -> Vec<Point3D<T>> {
+        [
+            (T::ZERO, -T::ONE, T::ZERO),
+            (T::ZERO, T::ONE, T::ZERO),
+            (-T::ONE, T::ZERO, T::ZERO),
+            (T::ONE, T::ZERO, T::ZERO),
+            (T::ZERO, T::ZERO, -T::ONE),
+            (T::ZERO, T::ZERO, T::ONE),
+        ]
+        .map(|(x, y, z)| *self + Point3D { x, y, z })
+        .to_vec()
+    }
+}
+
+impl<T: Coordinate> Point3D<T> {
+    pub fn new(x: T, y: T, z: T) -> Point3D<T> {
+        Point3D { x, y, z }
+    }
+
+    pub fn from_usize(x: usize, y: usize, z: usize) -> Point3D<T> {
+        Point3D {
+            x: T::from_usize(x),
+            y: T::from_usize(y),
+            z: T::from_usize(z),
+        }
+    }
+
+    pub fn get_manhattan_distance(&self, other: &Point3D<T>) -> usize {
+        (self.x - other.x).magnitude()
+            + (self.y - other.y).magnitude()
+            + (self.z - other.z).magnitude()
+    }
+
+    pub fn get_euclidean_distance(&self, other: &Point3D<T>) -> f64 {
+        let difference = *self - *other;
+        ((difference.x * difference.x + difference.y * difference.y + difference.z * difference.z)
+            .magnitude() as f64)
+            .sqrt()
+    }
+}
+
+impl<T: Coordinate> Point<T, 3> for Point3D<T> {
+    fn from_coordinates([x, y, z]: [T; 3]) -> Point3D<T> {
+        Point3D { x, y, z }
+    }
+
+    fn coordinates(&self) -> [T; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+impl_point_ops!(Point3D, x, y, z);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_operators() {
+        let mut point = Point3D { x: 3, y: -4, z: 5 };
+        let other = Point3D { x: 1, y: 1, z: 1 };
+        assert_eq!(Point3D { x: 4, y: -3, z: 6 }, point + other);
+        assert_eq!(Point3D { x: 2, y: -5, z: 4 }, point - other);
+        assert_eq!(Point3D { x: -3, y: 4, z: -5 }, -point);
+        assert_eq!(Point3D { x: 6, y: -8, z: 10 }, point * 2);
+        assert_eq!(Point3D { x: 1, y: -2, z: 2 }, point / 2);
+        point += other;
+        point -= Point3D { x: 0, y: 0, z: 2 };
+        assert_eq!(Point3D { x: 4, y: -3, z: 4 }, point);
+        point *= 3;
+        assert_eq!(
+            Point3D {
+                x: 12,
+                y: -9,
+                z: 12
+            },
+            point
+        );
+        point /= 4;
+        assert_eq!(Point3D { x: 3, y: -2, z: 3 }, point);
+    }
+
+    #[test]
+    fn test_reference_operators() {
+        let point = Point3D { x: 3, y: -4, z: 5 };
+        let other = Point3D { x: 1, y: 1, z: 1 };
+        // As references, the way they'd come out of a collection
+        let (by_ref, other_by_ref) = (&point, &other);
+        assert_eq!(point + other, by_ref + other_by_ref);
+        assert_eq!(point - other, by_ref - other_by_ref);
+        assert_eq!(-point, -by_ref);
+        assert_eq!(point * 2, by_ref * 2);
+        assert_eq!(point / 2, by_ref / 2);
+    }
+}
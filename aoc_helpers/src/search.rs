@@ -0,0 +1,332 @@
+//! Shortest paths over a state space that's described by a neighbour function, so a day only
+//! has to say what its states are and where each one can go.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult<S> {
+    pub cost: usize,
+    /// From the start to the goal, both included
+    pub path: Vec<S>,
+}
+
+/// Every state seen so far gets an index, so the queue and the back links don't need the
+/// states themselves to be `Ord` or cheap to clone
+struct Visited<S> {
+    states: Vec<S>,
+    indices: HashMap<S, usize>,
+    costs: Vec<usize>,
+    /// The states each one was reached from at its best cost. More than one if there are ties.
+    parents: Vec<Vec<usize>>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new(start: S) -> Visited<S> {
+        Visited {
+            states: vec![start.clone()],
+            indices: HashMap::from([(start, 0)]),
+            costs: vec![0],
+            parents: vec![vec![]],
+        }
+    }
+
+    /// Records reaching `state` from `parent` at `cost`, returning its index if that's at
+    /// least as good as any earlier way there, along with whether it's strictly better
+    fn reach(&mut self, state: S, parent: usize, cost: usize) -> Option<(usize, bool)> {
+        match self.indices.get(&state) {
+            // Nothing beats the start, and a zero cost step back to it mustn't become its parent
+            Some(0) => None,
+            Some(&index) if cost > self.costs[index] => None,
+            Some(&index) if cost == self.costs[index] => {
+                self.parents[index].push(parent);
+                Some((index, false))
+            }
+            Some(&index) => {
+                self.costs[index] = cost;
+                self.parents[index] = vec![parent];
+                Some((index, true))
+            }
+            None => {
+                let index = self.states.len();
+                self.indices.insert(state.clone(), index);
+                self.states.push(state);
+                self.costs.push(cost);
+                self.parents.push(vec![parent]);
+                Some((index, true))
+            }
+        }
+    }
+
+    /// Follows the first parent of each state back to the start
+    fn path_to(&self, mut index: usize) -> Vec<S> {
+        let mut path = vec![self.states[index].clone()];
+        while let Some(&parent) = self.parents[index].first() {
+            path.push(self.states[parent].clone());
+            index = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Every state that's on any of the best paths to the given goals
+    fn on_any_path(&self, goals: &[usize]) -> HashSet<S> {
+        let mut seen: HashSet<usize> = goals.iter().copied().collect();
+        let mut stack = goals.to_vec();
+        while let Some(index) = stack.pop() {
+            for &parent in &self.parents[index] {
+                if seen.insert(parent) {
+                    stack.push(parent);
+                }
+            }
+        }
+        seen.into_iter()
+            .map(|index| self.states[index].clone())
+            .collect()
+    }
+}
+
+/// Breadth first search, for when every step costs 1
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&visited.states[index]) {
+            return Some(SearchResult {
+                cost: visited.costs[index],
+                path: visited.path_to(index),
+            });
+        }
+        let cost = visited.costs[index] + 1;
+        for neighbour in neighbours(&visited.states[index]) {
+            if !visited.indices.contains_key(&neighbour) {
+                if let Some((next, _)) = visited.reach(neighbour, index, cost) {
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// How many steps away every reachable state is, e.g. to look up distances from both ends of
+/// a race track
+pub fn bfs_distances<S, I>(start: S, mut neighbours: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        let distance = distances[&state] + 1;
+        for neighbour in neighbours(&state) {
+            if !distances.contains_key(&neighbour) {
+                distances.insert(neighbour.clone(), distance);
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    distances
+}
+
+/// A* with a heuristic that must never overestimate the remaining cost. The neighbour
+/// function gives each next state with the cost of the step to it.
+pub fn astar<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut visited = Visited::new(start);
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&visited.states[0]), 0, 0))]);
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        if cost > visited.costs[index] {
+            continue;
+        }
+        if is_goal(&visited.states[index]) {
+            return Some(SearchResult {
+                cost,
+                path: visited.path_to(index),
+            });
+        }
+        for (neighbour, step) in neighbours(&visited.states[index]) {
+            if let Some((next, true)) = visited.reach(neighbour, index, cost + step) {
+                let estimate = cost + step + heuristic(&visited.states[next]);
+                heap.push(Reverse((estimate, cost + step, next)));
+            }
+        }
+    }
+    None
+}
+
+pub fn dijkstra<S, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(start, neighbours, |_| 0, is_goal)
+}
+
+#[derive(Debug, Clone)]
+pub struct AllShortestPaths<S> {
+    pub cost: usize,
+    /// One of the best paths
+    pub path: Vec<S>,
+    /// Every state on any of the best paths, to any goal
+    pub on_any_path: HashSet<S>,
+}
+
+/// Dijkstra that keeps going until every equally good way to a goal is found
+pub fn dijkstra_all<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<AllShortestPaths<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut visited = Visited::new(start);
+    let mut heap = BinaryHeap::from([Reverse((0, 0))]);
+    let mut goals = vec![];
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        if cost > visited.costs[index] {
+            continue;
+        }
+        if goals
+            .first()
+            .is_some_and(|&goal| cost > visited.costs[goal])
+        {
+            break;
+        }
+        if is_goal(&visited.states[index]) {
+            goals.push(index);
+            continue;
+        }
+        for (neighbour, step) in neighbours(&visited.states[index]) {
+            if let Some((next, true)) = visited.reach(neighbour, index, cost + step) {
+                heap.push(Reverse((cost + step, next)));
+            }
+        }
+    }
+    let &goal = goals.first()?;
+    Some(AllShortestPaths {
+        cost: visited.costs[goal],
+        path: visited.path_to(goal),
+        on_any_path: visited.on_any_path(&goals),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// A 5x5 room with a wall down the middle that has a gap at the bottom
+    fn open_neighbours(&(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+            .filter(|&(x, y)| !(x == 2 && y < 4))
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let result = bfs((0, 0), open_neighbours, |&state| state == (4, 0)).unwrap();
+        assert_eq!(12, result.cost);
+        assert_eq!(13, result.path.len());
+        assert_eq!(Some(&(0, 0)), result.path.first());
+        assert_eq!(Some(&(2, 4)), result.path.get(6));
+        assert_eq!(None, bfs((0, 0), open_neighbours, |&state| state == (2, 0)));
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        let distances = bfs_distances((0, 0), open_neighbours);
+        assert_eq!(Some(&12), distances.get(&(4, 0)));
+        assert_eq!(None, distances.get(&(2, 0)));
+        assert_eq!(25 - 4, distances.len());
+    }
+
+    #[test]
+    fn test_dijkstra_prefers_cheaper_steps() {
+        // Going straight from 0 to 3 costs 10, but the long way round costs 3
+        let edges = |&node: &u32| match node {
+            0 => vec![(3, 10), (1, 1)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        };
+        let result = dijkstra(0, edges, |&node| node == 3).unwrap();
+        assert_eq!(3, result.cost);
+        assert_eq!(vec![0, 1, 2, 3], result.path);
+    }
+
+    #[test]
+    fn test_zero_cost_steps() {
+        // 0 and 1 are free to move between, so 1 looks like a way back to the start
+        let edges = |&node: &u32| match node {
+            0 => vec![(1, 0)],
+            1 => vec![(0, 0), (2, 0), (3, 2)],
+            2 => vec![(1, 0), (3, 2)],
+            _ => vec![],
+        };
+        let result = dijkstra(0, edges, |&node| node == 3).unwrap();
+        assert_eq!(2, result.cost);
+        assert_eq!(vec![0, 1, 3], result.path);
+        let all = dijkstra_all(0, edges, |&node| node == 3).unwrap();
+        assert_eq!(2, all.cost);
+        assert_eq!(vec![0, 1, 3], all.path);
+        assert_eq!(HashSet::from([0, 1, 2, 3]), all.on_any_path);
+    }
+
+    #[test]
+    fn test_astar_matches_bfs() {
+        let weighted = |state: &(i32, i32)| {
+            open_neighbours(state)
+                .into_iter()
+                .map(|neighbour| (neighbour, 1))
+        };
+        let manhattan = |&(x, y): &(i32, i32)| ((4 - x).abs() + y.abs()) as usize;
+        let result = astar((0, 0), weighted, manhattan, |&state| state == (4, 0)).unwrap();
+        assert_eq!(12, result.cost);
+        assert_eq!(13, result.path.len());
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        // Two equally short ways from the top left to the bottom right of a 2x2 square
+        let moves = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| x < 2 && y < 2)
+                .map(|state| (state, 1))
+                .collect::<Vec<_>>()
+        };
+        let result = dijkstra_all((0, 0), moves, |&state| state == (1, 1)).unwrap();
+        assert_eq!(2, result.cost);
+        assert_eq!(3, result.path.len());
+        assert_eq!(
+            HashSet::from([(0, 0), (1, 0), (0, 1), (1, 1)]),
+            result.on_any_path
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! Disjoint sets, for grouping things that get joined together one pair at a time (circuits,
+//! regions of a grid, Kruskal's algorithm) without rescanning the groups on every join.
+//!
+//! Anything hashable can be a key. Keys are numbered as they're added and the sets are kept as
+//! a forest over those numbers, with path compression and union by rank, so every operation is
+//! close enough to constant time.
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone)]
+pub struct UnionFind<K> {
+    indices: HashMap<K, usize>,
+    keys: Vec<K>,
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    sizes: Vec<usize>,
+    component_count: usize,
+}
+
+impl<K> Default for UnionFind<K> {
+    fn default() -> Self {
+        UnionFind {
+            indices: HashMap::new(),
+            keys: Vec::new(),
+            parents: Vec::new(),
+            ranks: Vec::new(),
+            sizes: Vec::new(),
+            component_count: 0,
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone> FromIterator<K> for UnionFind<K> {
+    /// Every key in a component of its own
+    fn from_iter<I: IntoIterator<Item = K>>(keys: I) -> Self {
+        let mut union_find = UnionFind::new();
+        for key in keys {
+            union_find.insert(key);
+        }
+        union_find
+    }
+}
+
+impl<K: Eq + Hash + Clone> UnionFind<K> {
+    pub fn new() -> UnionFind<K> {
+        UnionFind::default()
+    }
+
+    /// The number of keys, across all components
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.component_count
+    }
+
+    /// Adds `key` in a component of its own. Returns false, and changes nothing, if it was
+    /// already there.
+    pub fn insert(&mut self, key: K) -> bool {
+        if self.contains(&key) {
+            return false;
+        }
+        self.index_of(&key);
+        true
+    }
+
+    fn index_of(&mut self, key: &K) -> usize {
+        if let Some(&index) = self.indices.get(key) {
+            return index;
+        }
+        let index = self.keys.len();
+        self.indices.insert(key.clone(), index);
+        self.keys.push(key.clone());
+        self.parents.push(index);
+        self.ranks.push(0);
+        self.sizes.push(1);
+        self.component_count += 1;
+        index
+    }
+
+    fn root(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        // Point everything on the way straight at the root, so the next lookup is quick
+        let mut current = index;
+        while self.parents[current] != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+        root
+    }
+
+    /// The key representing `key`'s component, which is the same for every key in it until the
+    /// component is joined to another, or `None` if `key` was never added
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let index = *self.indices.get(key)?;
+        let root = self.root(index);
+        Some(&self.keys[root])
+    }
+
+    /// Joins the components of `a` and `b`, adding either of them first if they're new. Returns
+    /// false if they were already in the same component.
+    pub fn union(&mut self, a: &K, b: &K) -> bool {
+        let a = self.index_of(a);
+        let b = self.index_of(b);
+        let (a, b) = (self.root(a), self.root(b));
+        if a == b {
+            return false;
+        }
+        // Hang the shallower tree under the deeper one, so trees only get deeper when they're
+        // the same depth
+        let (child, parent) = if self.ranks[a] < self.ranks[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parents[child] = parent;
+        self.sizes[parent] += self.sizes[child];
+        if self.ranks[child] == self.ranks[parent] {
+            self.ranks[parent] += 1;
+        }
+        self.component_count -= 1;
+        true
+    }
+
+    /// Whether `a` and `b` are in the same component. Keys that were never added aren't
+    /// connected to anything.
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.indices.get(a).copied(), self.indices.get(b).copied()) {
+            (Some(a), Some(b)) => self.root(a) == self.root(b),
+            _ => false,
+        }
+    }
+
+    /// The number of keys in `key`'s component, or `None` if `key` was never added
+    pub fn component_size(&mut self, key: &K) -> Option<usize> {
+        let index = *self.indices.get(key)?;
+        let root = self.root(index);
+        Some(self.sizes[root])
+    }
+
+    /// The size of every component, in no particular order
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.keys.len())
+            .filter(|&index| self.parents[index] == index)
+            .map(|root| self.sizes[root])
+            .collect()
+    }
+
+    /// The keys in each component, in no particular order
+    pub fn components(&mut self) -> Vec<Vec<K>> {
+        let mut by_root: HashMap<usize, Vec<K>> = HashMap::new();
+        for index in 0..self.keys.len() {
+            let root = self.root(index);
+            by_root
+                .entry(root)
+                .or_default()
+                .push(self.keys[index].clone());
+        }
+        by_root.into_values().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point2d::Point2D;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_union_find() {
+        let mut union_find: UnionFind<usize> = (0..10).collect();
+        assert_eq!(10, union_find.component_count());
+        assert!(union_find.union(&0, &1));
+        assert!(union_find.union(&2, &3));
+        assert!(union_find.union(&1, &3));
+        assert!(!union_find.union(&0, &2));
+        assert!(union_find.connected(&0, &3));
+        assert!(!union_find.connected(&0, &4));
+        assert!(!union_find.connected(&0, &10));
+        assert_eq!(union_find.find(&0).cloned(), union_find.find(&2).cloned());
+        assert_eq!(None, union_find.find(&10));
+        assert_eq!(Some(4), union_find.component_size(&2));
+        assert_eq!(Some(1), union_find.component_size(&9));
+        assert_eq!(7, union_find.component_count());
+
+        let mut sizes = union_find.component_sizes();
+        sizes.sort();
+        assert_eq!(vec![1, 1, 1, 1, 1, 1, 4], sizes);
+        let mut components = union_find.components();
+        components.iter_mut().for_each(|component| component.sort());
+        components.sort();
+        assert_eq!(vec![0, 1, 2, 3], components[0]);
+        assert_eq!(7, components.len());
+    }
+
+    #[test]
+    fn test_union_adds_new_keys() {
+        let mut union_find = UnionFind::new();
+        assert!(union_find.is_empty());
+        let a: Point2D = Point2D::new(0, 0);
+        let b = Point2D::new(0, 1);
+        assert!(union_find.union(&a, &b));
+        assert!(!union_find.insert(a));
+        assert!(union_find.insert(Point2D::new(5, 5)));
+        assert_eq!(3, union_find.len());
+        assert_eq!(2, union_find.component_count());
+        assert_eq!(Some(2), union_find.component_size(&b));
+    }
+}